@@ -8,15 +8,22 @@ try:
         Err,
         Error,
         ErrorKind,
+        Frame,
         None_,
         Ok,
         Option,
+        PyropustError,
         Result,
         Some,
         bail,
+        do,
         ensure,
         err,
         exception_to_error,
+        set_traceback_capture,
+        set_traceback_limit,
+        traceback_capture_enabled,
+        traceback_limit,
     )
 except ModuleNotFoundError as exc:
     raise ModuleNotFoundError(
@@ -42,14 +49,21 @@ __all__ = [
     "Error",
     "ErrorCode",
     "ErrorKind",
+    "Frame",
     "None_",
     "Ok",
     "Option",
+    "PyropustError",
     "Result",
     "Some",
     "bail",
     "catch",
+    "do",
     "ensure",
     "err",
     "exception_to_error",
+    "set_traceback_capture",
+    "set_traceback_limit",
+    "traceback_capture_enabled",
+    "traceback_limit",
 ]
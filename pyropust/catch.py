@@ -1,8 +1,9 @@
 from __future__ import annotations
 
-from collections.abc import Callable
+import inspect
+from collections.abc import Awaitable, Callable, Coroutine
 from functools import wraps
-from typing import overload
+from typing import Any, cast, overload
 
 from .pyropust_native import Result
 
@@ -11,13 +12,34 @@ def _is_exception_type(value: object) -> bool:
     return isinstance(value, type) and issubclass(value, BaseException)
 
 
+def _decorate_async[**P, R](
+    fn: Callable[P, Awaitable[R]],
+    exc_types: tuple[type[BaseException], ...],
+    keep_exception: bool,
+) -> Callable[P, Coroutine[Any, Any, Result[R]]]:
+    @wraps(fn)
+    async def wrapper(*args: P.args, **kwargs: P.kwargs) -> Result[R]:
+        return await Result.attempt_async(
+            lambda: fn(*args, **kwargs), *exc_types, keep_exception=keep_exception
+        )
+
+    return wrapper
+
+
 def _decorate[**P, R](
     fn: Callable[P, R],
     exc_types: tuple[type[BaseException], ...],
+    keep_exception: bool,
 ) -> Callable[P, Result[R]]:
+    if inspect.iscoroutinefunction(fn):
+        # Keep the wrapper a coroutine function so frameworks still detect it as async.
+        return cast("Callable[P, Result[R]]", _decorate_async(fn, exc_types, keep_exception))
+
     @wraps(fn)
     def wrapper(*args: P.args, **kwargs: P.kwargs) -> Result[R]:
-        return Result.attempt(lambda: fn(*args, **kwargs), *exc_types)
+        return Result.attempt(
+            lambda: fn(*args, **kwargs), *exc_types, keep_exception=keep_exception
+        )
 
     return wrapper
 
@@ -31,20 +53,24 @@ def catch[**P, R](fn: Callable[P, R], /) -> Callable[P, Result[R]]: ...
 @overload
 def catch[**P, R](
     *exc_types: type[BaseException],
+    keep_exception: bool = False,
 ) -> Callable[[Callable[P, R]], Callable[P, Result[R]]]: ...
 
 
 def catch[**P, R](
     *args: type[BaseException] | Callable[P, R],
+    keep_exception: bool = False,
 ) -> Callable[P, Result[R]] | Callable[[Callable[P, R]], Callable[P, Result[R]]]:
     """Convert exceptions into Result using Error.
 
     Can be used as @catch or @catch(ValueError, TypeError).
+    Coroutine functions stay async and resolve to a Result when awaited.
+    With keep_exception=True the caught exception is kept as Error.exception.
     """
     # Bare decorator usage: @catch
     if args and callable(args[0]) and not _is_exception_type(args[0]):
         fn: Callable[P, R] = args[0]  # type: ignore[assignment]
-        return _decorate(fn, (Exception,))
+        return _decorate(fn, (Exception,), keep_exception)
 
     # Decorator with arguments: @catch() or @catch(ValueError)
     exc_types: list[type[BaseException]] = []
@@ -57,6 +83,6 @@ def catch[**P, R](
         exc_types = [Exception]
 
     def decorator(fn: Callable[P, R]) -> Callable[P, Result[R]]:
-        return _decorate(fn, tuple(exc_types))
+        return _decorate(fn, tuple(exc_types), keep_exception)
 
     return decorator
@@ -4,9 +4,18 @@
 # This file provides type information for the native Rust module.
 # For the public API, see pyropust/__init__.pyi.
 
-from collections.abc import Callable, Mapping
+from collections.abc import (
+    AsyncGenerator,
+    Awaitable,
+    Callable,
+    Coroutine,
+    Generator,
+    Iterable,
+    Iterator,
+    Mapping,
+)
 from enum import StrEnum
-from typing import Generic, Never, TypeVar
+from typing import Any, Generic, Never, TypeVar, overload
 
 class ErrorCode(StrEnum): ...  # type: ignore[misc]
 
@@ -34,7 +43,7 @@ class Result(Generic[T_co]):
         code: str | ErrorCode,
         message: str,
         kind: ErrorKind | str | None = None,
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
         op: str | None = None,
         path: list[str | int] | None = None,
         expected: str | None = None,
@@ -47,7 +56,7 @@ class Result(Generic[T_co]):
         code: str | ErrorCode,
         message: str,
         kind: ErrorKind | str | None = None,
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
         op: str | None = None,
         path: list[str | int] | None = None,
         expected: str | None = None,
@@ -63,7 +72,7 @@ class Result(Generic[T_co]):
         message: str,
         *,
         code: str | ErrorCode = "context",
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
         op: str | None = None,
         path: list[str | int] | None = None,
         expected: str | None = None,
@@ -79,7 +88,128 @@ class Result(Generic[T_co]):
     def transpose[T](self: Result[Option[T]]) -> Option[Result[T]]: ...
     def unwrap_or_raise(self, exc: BaseException) -> T_co: ...
     @classmethod
-    def attempt[T](cls, f: Callable[[], T], *exceptions: type[BaseException]) -> Result[T]: ...
+    def attempt[T](
+        cls, f: Callable[[], T], *exceptions: type[BaseException], keep_exception: bool = False
+    ) -> Result[T]: ...
+    @classmethod
+    def attempt_async[T](
+        cls,
+        f: Awaitable[T] | Callable[[], Awaitable[T]],
+        *exceptions: type[BaseException],
+        keep_exception: bool = False,
+    ) -> Coroutine[Any, Any, Result[T]]: ...
+    @overload
+    @classmethod
+    def collect[T](cls, results: Iterable[Result[T]]) -> Result[list[T]]: ...
+    @overload
+    @classmethod
+    def collect[T](
+        cls, results: Iterable[Result[T]], *, into: type[tuple[Any, ...]]
+    ) -> Result[tuple[T, ...]]: ...
+    @overload
+    @classmethod
+    def collect[K, V](
+        cls, results: Iterable[Result[tuple[K, V]]], *, into: type[dict[Any, Any]]
+    ) -> Result[dict[K, V]]: ...
+    @overload
+    @classmethod
+    def collect[T, C](
+        cls, results: Iterable[Result[T]], *, into: Callable[[list[T]], C]
+    ) -> Result[C]: ...
+    def zip[U](self, other: Result[U]) -> Result[tuple[T_co, U]]: ...
+    def zip_with[U, R](self, other: Result[U], f: Callable[[T_co, U], R]) -> Result[R]: ...
+    @overload
+    @classmethod
+    def all[A](cls, a: Result[A], /) -> Result[tuple[A]]: ...
+    @overload
+    @classmethod
+    def all[A, B](cls, a: Result[A], b: Result[B], /) -> Result[tuple[A, B]]: ...
+    @overload
+    @classmethod
+    def all[A, B, C](
+        cls, a: Result[A], b: Result[B], c: Result[C], /
+    ) -> Result[tuple[A, B, C]]: ...
+    @overload
+    @classmethod
+    def all[A, B, C, D](
+        cls, a: Result[A], b: Result[B], c: Result[C], d: Result[D], /
+    ) -> Result[tuple[A, B, C, D]]: ...
+    @overload
+    @classmethod
+    def all[A, B, C, D, E](
+        cls, a: Result[A], b: Result[B], c: Result[C], d: Result[D], e: Result[E], /
+    ) -> Result[tuple[A, B, C, D, E]]: ...
+    @overload
+    @classmethod
+    def all(cls, *results: Result[Any]) -> Result[tuple[Any, ...]]: ...
+    @overload
+    @classmethod
+    def map_n[A, R](cls, f: Callable[[A], R], a: Result[A], /) -> Result[R]: ...
+    @overload
+    @classmethod
+    def map_n[A, B, R](cls, f: Callable[[A, B], R], a: Result[A], b: Result[B], /) -> Result[R]: ...
+    @overload
+    @classmethod
+    def map_n[A, B, C, R](
+        cls, f: Callable[[A, B, C], R], a: Result[A], b: Result[B], c: Result[C], /
+    ) -> Result[R]: ...
+    @overload
+    @classmethod
+    def map_n[A, B, C, D, R](
+        cls, f: Callable[[A, B, C, D], R], a: Result[A], b: Result[B], c: Result[C], d: Result[D], /
+    ) -> Result[R]: ...
+    @overload
+    @classmethod
+    def map_n[A, B, C, D, E, R](
+        cls,
+        f: Callable[[A, B, C, D, E], R],
+        a: Result[A],
+        b: Result[B],
+        c: Result[C],
+        d: Result[D],
+        e: Result[E],
+        /,
+    ) -> Result[R]: ...
+    @overload
+    @classmethod
+    def map_n[R](cls, f: Callable[..., R], *results: Result[Any]) -> Result[R]: ...
+    @classmethod
+    def partition[T](cls, results: Iterable[Result[T]]) -> tuple[list[T], list[Error]]: ...
+    @overload
+    @classmethod
+    def collect_all[T](
+        cls,
+        results: Iterable[Result[T]],
+        *,
+        code: str | ErrorCode = "collect_all",
+        message: str | None = None,
+    ) -> Result[list[T]]: ...
+    @overload
+    @classmethod
+    def collect_all[T, C](
+        cls,
+        results: Iterable[Result[T]],
+        *,
+        code: str | ErrorCode = "collect_all",
+        message: str | None = None,
+        into: Callable[[list[T]], C],
+    ) -> Result[C]: ...
+    def to_dict(self, *, encoder: Callable[[T_co], object] | None = None) -> dict[str, object]: ...
+    @overload
+    @classmethod
+    def from_dict(cls, data: dict[str, object]) -> Result[Any]: ...
+    @overload
+    @classmethod
+    def from_dict[T](cls, data: dict[str, object], *, decoder: Callable[[Any], T]) -> Result[T]: ...
+    def to_json(self, *, encoder: Callable[[T_co], object] | None = None) -> str: ...
+    @overload
+    @classmethod
+    def from_json(cls, data: str | bytes) -> Result[Any]: ...
+    @overload
+    @classmethod
+    def from_json[T](cls, data: str | bytes, *, decoder: Callable[[Any], T]) -> Result[T]: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
 
 class Option(Generic[T_co]):
     def is_some(self) -> bool: ...
@@ -98,7 +228,7 @@ class Option(Generic[T_co]):
         code: str | ErrorCode,
         message: str,
         kind: ErrorKind | str | None = None,
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
         op: str | None = None,
         path: list[str | int] | None = None,
         expected: str | None = None,
@@ -117,7 +247,7 @@ class Option(Generic[T_co]):
         code: str | ErrorCode,
         message: str,
         kind: ErrorKind | str | None = None,
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
         op: str | None = None,
         path: list[str | int] | None = None,
         expected: str | None = None,
@@ -136,12 +266,12 @@ class Option(Generic[T_co]):
         message: str,
         *,
         kind: ErrorKind | str | None = None,
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
         op: str | None = None,
         path: list[str | int] | None = None,
         expected: str | None = None,
         got: str | None = None,
-        cause: str | None = None,
+        cause: Error | str | None = None,
     ) -> Result[T_co]: ...
     def ok_or_else(
         self,
@@ -149,18 +279,70 @@ class Option(Generic[T_co]):
         f: Callable[[], str | Error],
         *,
         kind: ErrorKind | str | None = None,
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
         op: str | None = None,
         path: list[str | int] | None = None,
         expected: str | None = None,
         got: str | None = None,
-        cause: str | None = None,
+        cause: Error | str | None = None,
     ) -> Result[T_co]: ...
+    @overload
+    @classmethod
+    def collect[T](cls, options: Iterable[Option[T]]) -> Option[list[T]]: ...
+    @overload
+    @classmethod
+    def collect[T](
+        cls, options: Iterable[Option[T]], *, into: type[tuple[Any, ...]]
+    ) -> Option[tuple[T, ...]]: ...
+    @overload
+    @classmethod
+    def collect[K, V](
+        cls, options: Iterable[Option[tuple[K, V]]], *, into: type[dict[Any, Any]]
+    ) -> Option[dict[K, V]]: ...
+    @overload
+    @classmethod
+    def collect[T, C](
+        cls, options: Iterable[Option[T]], *, into: Callable[[list[T]], C]
+    ) -> Option[C]: ...
+    def to_dict(self, *, encoder: Callable[[T_co], object] | None = None) -> dict[str, object]: ...
+    @overload
+    @classmethod
+    def from_dict(cls, data: dict[str, object]) -> Option[Any]: ...
+    @overload
+    @classmethod
+    def from_dict[T](cls, data: dict[str, object], *, decoder: Callable[[Any], T]) -> Option[T]: ...
+    def to_json(self, *, encoder: Callable[[T_co], object] | None = None) -> str: ...
+    @overload
+    @classmethod
+    def from_json(cls, data: str | bytes) -> Option[Any]: ...
+    @overload
+    @classmethod
+    def from_json[T](cls, data: str | bytes, *, decoder: Callable[[Any], T]) -> Option[T]: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
 
 class ErrorKind:
     InvalidInput: ErrorKind
     NotFound: ErrorKind
     Internal: ErrorKind
+    PermissionDenied: ErrorKind
+    Conflict: ErrorKind
+    Timeout: ErrorKind
+    Unavailable: ErrorKind
+    Cancelled: ErrorKind
+    RateLimited: ErrorKind
+    Unauthenticated: ErrorKind
+    AlreadyExists: ErrorKind
+    @property
+    def value(self) -> str: ...
+    @classmethod
+    def from_str(cls, name: str) -> ErrorKind: ...
+    @classmethod
+    def register(cls, name: str) -> ErrorKind: ...
+    @classmethod
+    def all(cls) -> list[ErrorKind]: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
 
 class Error:
     @property
@@ -170,7 +352,7 @@ class Error:
     @property
     def message(self) -> str: ...
     @property
-    def metadata(self) -> dict[str, str]: ...
+    def metadata(self) -> dict[str, object]: ...
     @property
     def op(self) -> str | None: ...
     @property
@@ -180,10 +362,22 @@ class Error:
     @property
     def got(self) -> str | None: ...
     @property
-    def cause(self) -> str | None: ...
-    def to_dict(self) -> dict[str, object]: ...
+    def cause(self) -> Error | None: ...
+    @property
+    def children(self) -> list[Error]: ...
+    @property
+    def frames(self) -> list[Frame]: ...
+    def chain(self) -> Iterator[Error]: ...
+    def root_cause(self) -> Error: ...
+    @property
+    def exception(self) -> BaseException | None: ...
+    def reraise(self) -> Never: ...
+    def to_dict(self, *, strict: bool = False) -> dict[str, object]: ...
+    @classmethod
+    def from_dict(cls, data: dict[str, object], *, strict: bool = False) -> Error: ...
+    def to_json(self, *, strict: bool = False) -> str: ...
     @classmethod
-    def from_dict(cls, data: dict[str, object]) -> Error: ...
+    def from_json(cls, data: str | bytes, *, strict: bool = False) -> Error: ...
     @classmethod
     def new(
         cls,
@@ -195,7 +389,19 @@ class Error:
         path: list[str | int] | None = None,
         expected: str | None = None,
         got: str | None = None,
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
+    ) -> Error: ...
+    @classmethod
+    def group(
+        cls,
+        code: str | ErrorCode,
+        message: str,
+        errors: Iterable[Error],
+        *,
+        kind: ErrorKind | str | None = None,
+        op: str | None = None,
+        path: list[str | int] | None = None,
+        metadata: Mapping[str, object] | None = None,
     ) -> Error: ...
     @classmethod
     def wrap(
@@ -209,34 +415,59 @@ class Error:
         path: list[str | int] | None = None,
         expected: str | None = None,
         got: str | None = None,
-        metadata: Mapping[str, str] | None = None,
+        metadata: Mapping[str, object] | None = None,
+        keep_exception: bool = False,
     ) -> Error: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class Frame:
+    @property
+    def filename(self) -> str: ...
+    @property
+    def lineno(self) -> int: ...
+    @property
+    def function(self) -> str: ...
+    @property
+    def line(self) -> str | None: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class Ok(Result[T_co]):
+    __match_args__ = ("value",)
+    def __new__[T](cls, value: T) -> Result[T]: ...  # type: ignore[misc]
+    @property
+    def value(self) -> T_co: ...
+
+class Err(Result[Never]):
+    __match_args__ = ("error",)
+    def __new__(cls, error: Error) -> Result[Never]: ...  # type: ignore[misc]
+    @property
+    def error(self) -> Error: ...
 
-def Ok[T](value: T) -> Result[T]: ...
-def Err(error: Error) -> Result[Never]: ...
 def err(
     code: str | ErrorCode,
     message: str,
     *,
     kind: ErrorKind | str | None = None,
-    metadata: Mapping[str, str] | None = None,
+    metadata: Mapping[str, object] | None = None,
     op: str | None = None,
     path: list[str | int] | None = None,
     expected: str | None = None,
     got: str | None = None,
-    cause: str | None = None,
+    cause: Error | str | None = None,
 ) -> Result[Never]: ...
 def bail(
     code: str | ErrorCode,
     message: str,
     *,
     kind: ErrorKind | str | None = None,
-    metadata: Mapping[str, str] | None = None,
+    metadata: Mapping[str, object] | None = None,
     op: str | None = None,
     path: list[str | int] | None = None,
     expected: str | None = None,
     got: str | None = None,
-    cause: str | None = None,
+    cause: Error | str | None = None,
 ) -> Result[Never]: ...
 def ensure(
     condition: object,
@@ -244,13 +475,43 @@ def ensure(
     message: str,
     *,
     kind: ErrorKind | str | None = None,
-    metadata: Mapping[str, str] | None = None,
+    metadata: Mapping[str, object] | None = None,
     op: str | None = None,
     path: list[str | int] | None = None,
     expected: str | None = None,
     got: str | None = None,
-    cause: str | None = None,
+    cause: Error | str | None = None,
 ) -> Result[None]: ...
-def Some[T](value: T) -> Option[T]: ...
-def None_() -> Option[Never]: ...
-def exception_to_error(exc: BaseException, code: str = "py_exception") -> Error: ...
+
+class Some(Option[T_co]):
+    __match_args__ = ("value",)
+    def __new__[T](cls, value: T) -> Option[T]: ...  # type: ignore[misc]
+    @property
+    def value(self) -> T_co: ...
+
+class None_(Option[Never]):
+    __match_args__ = ()
+    def __new__(cls) -> Option[Never]: ...  # type: ignore[misc]
+
+class PyropustError(RuntimeError):
+    error: Error
+
+def exception_to_error(
+    exc: BaseException, code: str = "py_exception", *, keep_exception: bool = False
+) -> Error: ...
+def set_traceback_capture(*, enabled: bool) -> None: ...
+def traceback_capture_enabled() -> bool: ...
+def set_traceback_limit(limit: int | None) -> None: ...
+def traceback_limit() -> int | None: ...
+@overload
+def do[**P, R](fn: Callable[P, Generator[Result[Any], Any, R]], /) -> Callable[P, Result[R]]: ...
+@overload
+def do[**P, R](fn: Callable[P, Generator[Option[Any], Any, R]], /) -> Callable[P, Option[R]]: ...
+@overload
+def do[**P](
+    fn: Callable[P, AsyncGenerator[Result[Any], Any]], /
+) -> Callable[P, Coroutine[Any, Any, Result[Any]]]: ...
+@overload
+def do[**P](
+    fn: Callable[P, AsyncGenerator[Option[Any], Any]], /
+) -> Callable[P, Coroutine[Any, Any, Option[Any]]]: ...
@@ -1,21 +1,109 @@
-#[derive(Debug, Clone, Copy)]
+use std::str::FromStr;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ErrorKind {
     InvalidInput,
     NotFound,
     Internal,
+    PermissionDenied,
+    Conflict,
+    Timeout,
+    Unavailable,
+    Cancelled,
+    RateLimited,
+    Unauthenticated,
+    AlreadyExists,
+    /// Application-defined kind added through `register_kind`.
+    Custom(&'static str),
 }
 
 impl ErrorKind {
+    pub const BUILTIN: [ErrorKind; 11] = [
+        ErrorKind::InvalidInput,
+        ErrorKind::NotFound,
+        ErrorKind::Internal,
+        ErrorKind::PermissionDenied,
+        ErrorKind::Conflict,
+        ErrorKind::Timeout,
+        ErrorKind::Unavailable,
+        ErrorKind::Cancelled,
+        ErrorKind::RateLimited,
+        ErrorKind::Unauthenticated,
+        ErrorKind::AlreadyExists,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             ErrorKind::InvalidInput => "InvalidInput",
             ErrorKind::NotFound => "NotFound",
             ErrorKind::Internal => "Internal",
+            ErrorKind::PermissionDenied => "PermissionDenied",
+            ErrorKind::Conflict => "Conflict",
+            ErrorKind::Timeout => "Timeout",
+            ErrorKind::Unavailable => "Unavailable",
+            ErrorKind::Cancelled => "Cancelled",
+            ErrorKind::RateLimited => "RateLimited",
+            ErrorKind::Unauthenticated => "Unauthenticated",
+            ErrorKind::AlreadyExists => "AlreadyExists",
+            ErrorKind::Custom(name) => name,
         }
     }
+
+    /// Built-in kinds followed by registered custom kinds, in registration order.
+    pub fn all() -> Vec<ErrorKind> {
+        let mut kinds = ErrorKind::BUILTIN.to_vec();
+        let custom = CUSTOM_KINDS.lock().expect("kind registry");
+        kinds.extend(custom.iter().map(|name| ErrorKind::Custom(name)));
+        kinds
+    }
+}
+
+impl FromStr for ErrorKind {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        if let Some(kind) = ErrorKind::BUILTIN.iter().find(|k| k.as_str() == name) {
+            return Ok(*kind);
+        }
+        let custom = CUSTOM_KINDS.lock().expect("kind registry");
+        custom
+            .iter()
+            .find(|registered| **registered == name)
+            .map(|registered| ErrorKind::Custom(registered))
+            .ok_or(())
+    }
+}
+
+// Custom kinds live for the whole process, so their names are leaked once on
+// registration; this keeps `ErrorKind` `Copy`.
+static CUSTOM_KINDS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// Register (or look up) a kind by name. Registering an existing name is a no-op.
+pub fn register_kind(name: &str) -> Result<ErrorKind, String> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(format!(
+            "invalid ErrorKind name '{name}' (expected an identifier)"
+        ));
+    }
+    if let Some(kind) = ErrorKind::BUILTIN.iter().find(|k| k.as_str() == name) {
+        return Ok(*kind);
+    }
+    let mut custom = CUSTOM_KINDS.lock().expect("kind registry");
+    if let Some(registered) = custom.iter().find(|registered| **registered == name) {
+        return Ok(ErrorKind::Custom(registered));
+    }
+    let leaked: &'static str = Box::leak(name.to_string().into_boxed_str());
+    custom.push(leaked);
+    Ok(ErrorKind::Custom(leaked))
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PathItem {
     Key(String),
     Index(usize),
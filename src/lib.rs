@@ -2,27 +2,37 @@ mod error;
 mod py;
 
 use py::{
-    exception_to_error, py_bail_from_parts, py_ensure, py_err, py_err_from_parts, py_none, py_ok,
-    py_some, Error, ErrorKindObj, OptionObj, ResultObj,
+    exception_to_error, py_bail_from_parts, py_do, py_ensure, py_err_from_parts,
+    set_traceback_capture, set_traceback_limit, traceback_capture_enabled, traceback_limit, DoFn,
+    ErrObj, Error, ErrorKindObj, Frame, NoneObj, OkObj, OptionObj, PyropustError, ResultObj,
+    SomeObj,
 };
 use pyo3::prelude::*;
 use pyo3::types::PyModule;
 use pyo3::wrap_pyfunction;
 
-#[pymodule]
-fn pyropust_native(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+#[pymodule(gil_used = false)]
+fn pyropust_native(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<ResultObj>()?;
+    m.add_class::<OkObj>()?;
+    m.add_class::<ErrObj>()?;
     m.add_class::<OptionObj>()?;
+    m.add_class::<SomeObj>()?;
+    m.add_class::<NoneObj>()?;
     m.add_class::<ErrorKindObj>()?;
     m.add_class::<Error>()?;
-    m.add_function(wrap_pyfunction!(py_ok, m)?)?;
-    m.add_function(wrap_pyfunction!(py_err, m)?)?;
+    m.add_class::<Frame>()?;
+    m.add_class::<DoFn>()?;
+    m.add("PyropustError", py.get_type::<PyropustError>())?;
     m.add_function(wrap_pyfunction!(py_err_from_parts, m)?)?;
     m.add_function(wrap_pyfunction!(py_bail_from_parts, m)?)?;
     m.add_function(wrap_pyfunction!(py_ensure, m)?)?;
-    m.add_function(wrap_pyfunction!(py_some, m)?)?;
-    m.add_function(wrap_pyfunction!(py_none, m)?)?;
     m.add_function(wrap_pyfunction!(exception_to_error, m)?)?;
+    m.add_function(wrap_pyfunction!(py_do, m)?)?;
+    m.add_function(wrap_pyfunction!(set_traceback_capture, m)?)?;
+    m.add_function(wrap_pyfunction!(traceback_capture_enabled, m)?)?;
+    m.add_function(wrap_pyfunction!(set_traceback_limit, m)?)?;
+    m.add_function(wrap_pyfunction!(traceback_limit, m)?)?;
 
     m.add(
         "__all__",
@@ -36,8 +46,11 @@ fn pyropust_native(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
             "Some",
             "None_",
             "ensure",
+            "do",
             "Error",
             "ErrorKind",
+            "Frame",
+            "PyropustError",
         ],
     )?;
 
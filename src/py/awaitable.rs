@@ -0,0 +1,50 @@
+use pyo3::exceptions::{PyRuntimeError, PyStopIteration};
+use pyo3::prelude::*;
+use pyo3::types::{PyAny, PyIterator, PySendResult, PyTuple};
+
+// Helpers shared by the native awaitables. Each one is its own `__await__`
+// iterator and forwards event-loop traffic to the awaitable it is driving.
+
+/// What the event loop asked the awaitable to do.
+pub enum Resume<'py> {
+    Send(Bound<'py, PyAny>),
+    Throw(Bound<'py, PyTuple>),
+}
+
+/// Get the `__await__` iterator of an awaitable.
+pub fn await_iter<'py>(awaitable: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyIterator>> {
+    Ok(awaitable
+        .call_method0("__await__")?
+        .cast_into::<PyIterator>()?)
+}
+
+/// Forward `throw(*args)` into an inner iterator, reporting completion like `send`.
+pub fn throw_into<'py>(
+    inner: &Bound<'py, PyIterator>,
+    args: &Bound<'py, PyTuple>,
+) -> PyResult<PySendResult<'py>> {
+    let py = inner.py();
+    match inner.call_method1("throw", args) {
+        Ok(value) => Ok(PySendResult::Next(value)),
+        Err(e) if e.is_instance_of::<PyStopIteration>(py) => {
+            let value = e.value(py).getattr("value")?;
+            Ok(PySendResult::Return(value))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Finish an `__await__` iterator with `value` as the result of the `await`.
+pub fn stop_iteration(value: Py<PyAny>) -> PyErr {
+    PyStopIteration::new_err((value,))
+}
+
+/// `asyncio.CancelledError` must always propagate, whatever the caller asked to catch.
+pub fn is_cancelled(py: Python<'_>, err: &PyErr) -> PyResult<bool> {
+    let cancelled = py.import("asyncio")?.getattr("CancelledError")?;
+    Ok(err.is_instance(py, &cancelled))
+}
+
+pub fn reused_awaitable() -> PyErr {
+    PyRuntimeError::new_err("cannot reuse already awaited coroutine")
+}
@@ -0,0 +1,360 @@
+use pyo3::exceptions::{PyStopAsyncIteration, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::{PyAny, PyDict, PyIterator, PySendResult, PyString, PyTuple, PyType};
+use pyo3::{PyTraverseError, PyVisit};
+
+use super::awaitable::{await_iter, reused_awaitable, stop_iteration, throw_into, Resume};
+use super::option::{some, OptionObj};
+use super::result::{ok, ResultObj};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DoMode {
+    Result,
+    Option,
+}
+
+impl DoMode {
+    fn name(self) -> &'static str {
+        match self {
+            DoMode::Result => "Result",
+            DoMode::Option => "Option",
+        }
+    }
+}
+
+/// Generator function wrapped by `@do`.
+///
+/// Calling it drives the generator: every yielded `Result`/`Option` is
+/// unwrapped and its payload sent back, the first `Err`/`None_` short-circuits,
+/// and the return value is wrapped in `Ok`/`Some`. Async generator functions
+/// return a `DoAwaitable` instead.
+#[pyclass(frozen, dict, name = "Do", module = "pyropust")]
+pub struct DoFn {
+    func: Py<PyAny>,
+}
+
+#[pymethods]
+impl DoFn {
+    #[pyo3(signature = (*args, **kwargs))]
+    fn __call__(
+        &self,
+        py: Python<'_>,
+        args: &Bound<'_, PyTuple>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<PyAny>> {
+        let gen = self.func.bind(py).call(args, kwargs)?;
+        if gen.hasattr("asend")? {
+            let first = gen.call_method1("asend", (py.None(),))?;
+            let awaitable = DoAwaitable {
+                inner: Some(await_iter(&first)?.unbind()),
+                agen: gen.unbind(),
+                mode: None,
+                last: None,
+                phase: Phase::Running,
+            };
+            return Ok(Py::new(py, awaitable)?.into_any());
+        }
+        if gen.hasattr("send")? {
+            if let Ok(gen) = gen.cast::<PyIterator>() {
+                return run_generator(py, gen.clone());
+            }
+        }
+        if gen.hasattr("close")? {
+            // Don't leave an un-awaited coroutine behind.
+            gen.call_method0("close")?;
+        }
+        Err(PyTypeError::new_err(
+            "do expects a generator or async generator function (use yield inside the body)",
+        ))
+    }
+
+    // Pickle by reference, like the function it wraps.
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+        slf.getattr("__qualname__")
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.func)
+    }
+
+    /// The wrapped function. A getter rather than a `__dict__` entry because the GC
+    /// does not see into the instance dict, and recursive functions reference their
+    /// wrapper.
+    #[getter]
+    fn __wrapped__(&self, py: Python<'_>) -> Py<PyAny> {
+        self.func.clone_ref(py)
+    }
+
+    // Bind like a plain function so @do works on methods.
+    fn __get__(
+        slf: &Bound<'_, Self>,
+        instance: Option<&Bound<'_, PyAny>>,
+        _owner: Option<&Bound<'_, PyType>>,
+    ) -> PyResult<Py<PyAny>> {
+        let py = slf.py();
+        match instance {
+            Some(obj) if !obj.is_none() => {
+                let method_type = py.import("types")?.getattr("MethodType")?;
+                Ok(method_type.call1((slf, obj))?.unbind())
+            }
+            _ => Ok(slf.clone().into_any().unbind()),
+        }
+    }
+}
+
+enum Phase {
+    Running,
+    // Waiting for `aclose()` before reporting the outcome.
+    Closing(PyResult<Py<PyAny>>),
+    Done,
+}
+
+/// Awaitable returned by `@do` on an async generator function.
+///
+/// Async generators cannot `return` a value, so the block evaluates to the
+/// last `Result`/`Option` it yielded (`Ok(None)` when nothing was yielded).
+/// The first `Err`/`None_` still short-circuits and closes the generator.
+#[pyclass(name = "DoAwaitable")]
+pub struct DoAwaitable {
+    agen: Py<PyAny>,
+    inner: Option<Py<PyIterator>>,
+    mode: Option<DoMode>,
+    last: Option<Py<PyAny>>,
+    phase: Phase,
+}
+
+#[pymethods]
+impl DoAwaitable {
+    fn __await__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.resume(py, Resume::Send(py.None().into_bound(py)))
+    }
+
+    fn send(&mut self, py: Python<'_>, value: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        self.resume(py, Resume::Send(value))
+    }
+
+    #[pyo3(signature = (*args))]
+    fn throw(&mut self, py: Python<'_>, args: Bound<'_, PyTuple>) -> PyResult<Py<PyAny>> {
+        self.resume(py, Resume::Throw(args))
+    }
+
+    fn close(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.phase = Phase::Done;
+        if let Some(inner) = self.inner.take() {
+            inner.bind(py).call_method0("close")?;
+        }
+        Ok(())
+    }
+
+    // A pending outcome that is an error is not reported: a `PyErr` cannot be
+    // inspected without attaching to the interpreter.
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.agen)?;
+        visit.call(&self.inner)?;
+        visit.call(&self.last)?;
+        if let Phase::Closing(Ok(value)) = &self.phase {
+            visit.call(value)?;
+        }
+        Ok(())
+    }
+
+    fn __clear__(&mut self) {
+        self.inner = None;
+        self.last = None;
+        self.phase = Phase::Done;
+    }
+}
+
+impl DoAwaitable {
+    fn resume<'py>(&mut self, py: Python<'py>, action: Resume<'py>) -> PyResult<Py<PyAny>> {
+        let out = self.drive(py, action);
+        // Completion is reported through StopIteration, so any error ends the await.
+        if out.is_err() {
+            self.phase = Phase::Done;
+            self.inner = None;
+        }
+        out
+    }
+
+    fn drive<'py>(&mut self, py: Python<'py>, mut action: Resume<'py>) -> PyResult<Py<PyAny>> {
+        loop {
+            let Some(inner) = self.inner.as_ref().map(|i| i.bind(py).clone()) else {
+                return Err(reused_awaitable());
+            };
+            let outcome = match action {
+                Resume::Send(value) => inner.send(&value),
+                Resume::Throw(args) => throw_into(&inner, &args),
+            };
+            action = Resume::Send(py.None().into_bound(py));
+
+            let yielded = match outcome {
+                Ok(PySendResult::Next(value)) => return Ok(value.unbind()),
+                Ok(PySendResult::Return(yielded)) => yielded,
+                Err(e)
+                    if matches!(self.phase, Phase::Running)
+                        && e.is_instance_of::<PyStopAsyncIteration>(py) =>
+                {
+                    let value = match self.last.take() {
+                        Some(last) => last,
+                        None => ok(py, py.None())?.into_any(),
+                    };
+                    return Err(stop_iteration(value));
+                }
+                Err(e) => return Err(e),
+            };
+
+            if let Phase::Closing(_) = self.phase {
+                let Phase::Closing(outcome) = std::mem::replace(&mut self.phase, Phase::Done)
+                else {
+                    unreachable!()
+                };
+                return Err(match outcome {
+                    Ok(value) => stop_iteration(value),
+                    Err(e) => e,
+                });
+            }
+
+            let agen = self.agen.bind(py);
+            let next = match step(py, &mut self.mode, &yielded) {
+                Ok(Some(payload)) => {
+                    self.last = Some(yielded.unbind());
+                    agen.call_method1("asend", (payload,))?
+                }
+                Ok(None) => {
+                    self.phase = Phase::Closing(Ok(yielded.unbind()));
+                    agen.call_method0("aclose")?
+                }
+                Err(e) => {
+                    self.phase = Phase::Closing(Err(e));
+                    agen.call_method0("aclose")?
+                }
+            };
+            self.inner = Some(await_iter(&next)?.unbind());
+        }
+    }
+}
+
+#[pyfunction(name = "do")]
+pub fn py_do(py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Py<DoFn>> {
+    if !f.is_callable() {
+        return Err(PyTypeError::new_err("do expects a callable"));
+    }
+    let wrapper = Py::new(
+        py,
+        DoFn {
+            func: f.clone().unbind(),
+        },
+    )?;
+    update_wrapper(wrapper.bind(py), &f)?;
+    let inspect = py.import("inspect")?;
+    if inspect
+        .call_method1("isasyncgenfunction", (&f,))?
+        .is_truthy()?
+    {
+        // Calling the wrapper returns an awaitable, so frameworks must see a coroutine
+        // function (e.g. to await a FastAPI handler).
+        inspect.call_method1("markcoroutinefunction", (wrapper.bind(py),))?;
+    }
+    Ok(wrapper)
+}
+
+/// `functools.update_wrapper` minus `__wrapped__`, which `Do` exposes as a getter.
+fn update_wrapper(wrapper: &Bound<'_, DoFn>, f: &Bound<'_, PyAny>) -> PyResult<()> {
+    let functools = wrapper.py().import("functools")?;
+    for name in functools.getattr("WRAPPER_ASSIGNMENTS")?.try_iter()? {
+        let name = name?.cast_into::<PyString>()?;
+        if let Ok(value) = f.getattr(&name) {
+            wrapper.setattr(&name, value)?;
+        }
+    }
+    if let Ok(attributes) = f.getattr("__dict__") {
+        wrapper
+            .getattr("__dict__")?
+            .call_method1("update", (attributes,))?;
+    }
+    Ok(())
+}
+
+fn run_generator(py: Python<'_>, gen: Bound<'_, PyIterator>) -> PyResult<Py<PyAny>> {
+    let mut mode: Option<DoMode> = None;
+    let mut sent = py.None().into_bound(py);
+    loop {
+        let yielded = match gen.send(&sent)? {
+            PySendResult::Next(value) => value,
+            PySendResult::Return(value) => return finish(py, mode, value),
+        };
+        match step(py, &mut mode, &yielded) {
+            Ok(Some(payload)) => sent = payload,
+            Ok(None) => {
+                gen.call_method0("close")?;
+                return Ok(yielded.unbind());
+            }
+            Err(e) => {
+                gen.call_method0("close")?;
+                return Err(e);
+            }
+        }
+    }
+}
+
+/// Unwrap a yielded value, returning `None` when the block must short-circuit.
+fn step<'py>(
+    py: Python<'py>,
+    mode: &mut Option<DoMode>,
+    yielded: &Bound<'py, PyAny>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let (current, payload) = if let Ok(res) = yielded.extract::<PyRef<'_, ResultObj>>() {
+        let payload = if res.is_ok {
+            Some(res.ok.as_ref().expect("ok value").bind(py).clone())
+        } else {
+            None
+        };
+        (DoMode::Result, payload)
+    } else if let Ok(opt) = yielded.extract::<PyRef<'_, OptionObj>>() {
+        let payload = if opt.is_some {
+            Some(opt.value.as_ref().expect("some value").bind(py).clone())
+        } else {
+            None
+        };
+        (DoMode::Option, payload)
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "do block must yield Result or Option, got {}",
+            yielded.get_type().name()?
+        )));
+    };
+
+    match *mode {
+        Some(expected) if expected != current => Err(PyTypeError::new_err(format!(
+            "do block mixes Result and Option (expected {}, got {})",
+            expected.name(),
+            current.name()
+        ))),
+        _ => {
+            *mode = Some(current);
+            Ok(payload)
+        }
+    }
+}
+
+// A returned Result/Option of the block's own kind is passed through, mirroring
+// Result.attempt; anything else is wrapped.
+fn finish(py: Python<'_>, mode: Option<DoMode>, value: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+    let is_result = value.is_instance_of::<ResultObj>();
+    let is_option = value.is_instance_of::<OptionObj>();
+    match mode {
+        Some(DoMode::Option) if is_option => Ok(value.unbind()),
+        Some(DoMode::Option) => Ok(some(py, value.unbind())?.into_any()),
+        None if is_option => Ok(value.unbind()),
+        _ if is_result => Ok(value.unbind()),
+        _ => Ok(ok(py, value.unbind())?.into_any()),
+    }
+}
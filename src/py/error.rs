@@ -1,12 +1,44 @@
-use pyo3::exceptions::{PyBaseException, PyTypeError};
+use pyo3::create_exception;
+use pyo3::exceptions::{
+    PyBaseException, PyBaseExceptionGroup, PyRuntimeError, PyTypeError, PyValueError,
+};
+use pyo3::ffi;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyList, PyString, PyType};
+use pyo3::types::{PyAny, PyDict, PyIterator, PyList, PyString, PyType};
+use pyo3::{PyTraverseError, PyVisit};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use super::json::{error_from_json_document, parse_json_document};
+use super::metadata::{
+    extract_metadata, hash_metadata, metadata_eq, metadata_from_dict, metadata_to_dict, Metadata,
+};
+use super::traceback::{Frame, LazyTraceback};
 
 // Re-export from ops to avoid duplication
-pub use crate::error::{ErrorKind, PathItem};
+pub use crate::error::{register_kind, ErrorKind, PathItem};
+
+create_exception!(
+    pyropust,
+    PyropustError,
+    PyRuntimeError,
+    "Raised by `unwrap()` / `expect()` on `Err`; the `Error` is available as `.error`."
+);
+
+/// Build a `PyropustError` carrying `error` (the Err payload) as `.error`.
+pub fn pyropust_error(py: Python<'_>, message: String, error: &Py<PyAny>) -> PyErr {
+    let exc = PyropustError::new_err(message);
+    if let Err(err) = exc.value(py).setattr("error", error.clone_ref(py)) {
+        return err;
+    }
+    exc
+}
 
-#[pyclass(frozen, name = "ErrorKind")]
+#[pyclass(frozen, name = "ErrorKind", module = "pyropust")]
 #[derive(Clone)]
 pub struct ErrorKindObj {
     pub kind: ErrorKind,
@@ -17,35 +49,87 @@ pub struct ErrorKindObj {
 impl ErrorKindObj {
     #[classattr]
     fn InvalidInput(py: Python<'_>) -> Py<ErrorKindObj> {
-        Py::new(
-            py,
-            ErrorKindObj {
-                kind: ErrorKind::InvalidInput,
-            },
-        )
-        .expect("ErrorKind alloc")
+        kind_attr(py, ErrorKind::InvalidInput)
     }
 
     #[classattr]
     fn NotFound(py: Python<'_>) -> Py<ErrorKindObj> {
-        Py::new(
-            py,
-            ErrorKindObj {
-                kind: ErrorKind::NotFound,
-            },
-        )
-        .expect("ErrorKind alloc")
+        kind_attr(py, ErrorKind::NotFound)
     }
 
     #[classattr]
     fn Internal(py: Python<'_>) -> Py<ErrorKindObj> {
-        Py::new(
-            py,
-            ErrorKindObj {
-                kind: ErrorKind::Internal,
-            },
-        )
-        .expect("ErrorKind alloc")
+        kind_attr(py, ErrorKind::Internal)
+    }
+
+    #[classattr]
+    fn PermissionDenied(py: Python<'_>) -> Py<ErrorKindObj> {
+        kind_attr(py, ErrorKind::PermissionDenied)
+    }
+
+    #[classattr]
+    fn Conflict(py: Python<'_>) -> Py<ErrorKindObj> {
+        kind_attr(py, ErrorKind::Conflict)
+    }
+
+    #[classattr]
+    fn Timeout(py: Python<'_>) -> Py<ErrorKindObj> {
+        kind_attr(py, ErrorKind::Timeout)
+    }
+
+    #[classattr]
+    fn Unavailable(py: Python<'_>) -> Py<ErrorKindObj> {
+        kind_attr(py, ErrorKind::Unavailable)
+    }
+
+    #[classattr]
+    fn Cancelled(py: Python<'_>) -> Py<ErrorKindObj> {
+        kind_attr(py, ErrorKind::Cancelled)
+    }
+
+    #[classattr]
+    fn RateLimited(py: Python<'_>) -> Py<ErrorKindObj> {
+        kind_attr(py, ErrorKind::RateLimited)
+    }
+
+    #[classattr]
+    fn Unauthenticated(py: Python<'_>) -> Py<ErrorKindObj> {
+        kind_attr(py, ErrorKind::Unauthenticated)
+    }
+
+    #[classattr]
+    fn AlreadyExists(py: Python<'_>) -> Py<ErrorKindObj> {
+        kind_attr(py, ErrorKind::AlreadyExists)
+    }
+
+    #[getter]
+    fn value(&self) -> &'static str {
+        self.kind.as_str()
+    }
+
+    /// Look up a built-in or registered kind by name.
+    #[classmethod]
+    fn from_str(_cls: &Bound<'_, PyType>, name: &str) -> PyResult<Self> {
+        let kind = name
+            .parse::<ErrorKind>()
+            .map_err(|_| PyValueError::new_err(format!("unknown ErrorKind '{name}'")))?;
+        Ok(ErrorKindObj { kind })
+    }
+
+    /// Register an application-defined kind; returns the existing kind if the name is taken.
+    #[classmethod]
+    fn register(_cls: &Bound<'_, PyType>, name: &str) -> PyResult<Self> {
+        let kind = register_kind(name).map_err(PyValueError::new_err)?;
+        Ok(ErrorKindObj { kind })
+    }
+
+    /// All kinds: built-ins first, then registered kinds in registration order.
+    #[classmethod]
+    fn all(_cls: &Bound<'_, PyType>) -> Vec<ErrorKindObj> {
+        ErrorKind::all()
+            .into_iter()
+            .map(|kind| ErrorKindObj { kind })
+            .collect()
     }
 
     fn __repr__(&self) -> String {
@@ -65,20 +149,52 @@ impl ErrorKindObj {
     fn __eq__(&self, other: PyRef<'_, ErrorKindObj>) -> bool {
         self.kind.as_str() == other.kind.as_str()
     }
+
+    fn __hash__(&self) -> u64 {
+        stable_hash(&self.kind)
+    }
+
+    // Unpickle through `ErrorKind.register` so custom kinds also exist in the
+    // receiving process; for built-in names it is a plain lookup.
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<(Bound<'py, PyAny>, (&'static str,))> {
+        let register = py.get_type::<ErrorKindObj>().getattr("register")?;
+        Ok((register, (self.kind.as_str(),)))
+    }
+
+    fn __copy__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: PyRef<'py, Self>, _memo: Bound<'py, PyAny>) -> PyRef<'py, Self> {
+        slf
+    }
+}
+
+fn kind_attr(py: Python<'_>, kind: ErrorKind) -> Py<ErrorKindObj> {
+    Py::new(py, ErrorKindObj { kind }).expect("ErrorKind alloc")
 }
 
-#[pyclass(frozen, name = "Error")]
+#[pyclass(frozen, name = "Error", module = "pyropust")]
 #[derive(Clone)]
 pub struct Error {
     pub kind: ErrorKind,
     pub code: String,
     pub message: String,
-    pub metadata: HashMap<String, String>,
+    pub metadata: Metadata,
     pub op: Option<String>,
     pub path: Vec<PathItem>,
     pub expected: Option<String>,
     pub got: Option<String>,
-    pub cause: Option<String>,
+    /// Shared, so wrapping an error and reading `cause` never copy the rest of the chain.
+    pub cause: Option<Arc<Error>>,
+    /// Sub-errors of an aggregate (e.g. one per exception of an `ExceptionGroup`).
+    pub children: Vec<Error>,
+    /// Originating exception, kept only with `keep_exception=True`. Not part of
+    /// equality, hashing or the dict/JSON/pickle formats.
+    pub exception: Option<Arc<Py<PyAny>>>,
+    /// Tracebacks captured at conversion and formatted on first read, keyed by the
+    /// metadata entry they fill (`py_traceback`, `cause_py_traceback`).
+    pub tracebacks: Vec<(&'static str, Arc<LazyTraceback>)>,
 }
 
 #[pymethods]
@@ -100,11 +216,7 @@ impl Error {
 
     #[getter]
     fn metadata(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let dict = PyDict::new(py);
-        for (k, v) in &self.metadata {
-            dict.set_item(k, v)?;
-        }
-        Ok(dict.into())
+        Ok(metadata_to_dict(py, &self.resolved_metadata(), false)?.into())
     }
 
     #[getter]
@@ -139,23 +251,139 @@ impl Error {
     }
 
     #[getter]
-    fn cause(&self) -> Option<String> {
-        self.cause.clone()
+    fn cause(&self, py: Python<'_>) -> PyResult<Option<Py<Error>>> {
+        self.cause
+            .as_deref()
+            .map(|cause| Py::new(py, cause.clone()))
+            .transpose()
+    }
+
+    #[getter]
+    fn children(&self, py: Python<'_>) -> PyResult<Vec<Py<Error>>> {
+        self.children
+            .iter()
+            .map(|child| Py::new(py, child.clone()))
+            .collect()
+    }
+
+    /// Frames of the converted exception's traceback, outermost first; empty for
+    /// errors not converted from an exception (or with capture disabled).
+    #[getter]
+    fn frames(&self, py: Python<'_>) -> PyResult<Vec<Py<Frame>>> {
+        self.tracebacks
+            .iter()
+            .filter(|(key, _)| *key == "py_traceback")
+            .flat_map(|(_, traceback)| traceback.frames(py))
+            .map(|frame| Py::new(py, frame))
+            .collect()
+    }
+
+    /// Iterate over this error followed by each cause, outermost first.
+    fn chain<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyIterator>> {
+        let py = slf.py();
+        let items = PyList::new(py, [slf.clone().into_any()])?;
+        for cause in slf.get().layers().skip(1) {
+            items.append(Py::new(py, cause.clone())?)?;
+        }
+        items.try_iter()
+    }
+
+    /// The innermost error of the chain (this error when there is no cause).
+    fn root_cause(slf: &Bound<'_, Self>) -> PyResult<Py<Error>> {
+        let root = slf
+            .get()
+            .layers()
+            .last()
+            .expect("chain has the error itself");
+        if std::ptr::eq(root, slf.get()) {
+            Ok(slf.clone().unbind())
+        } else {
+            Py::new(slf.py(), root.clone())
+        }
+    }
+
+    #[getter]
+    fn exception(&self, py: Python<'_>) -> Option<Py<PyAny>> {
+        self.exception.as_deref().map(|exc| exc.clone_ref(py))
+    }
+
+    /// Re-raise the first retained exception in the chain with its original
+    /// traceback, or a `PyropustError` carrying this error when none was kept.
+    fn reraise(slf: &Bound<'_, Self>) -> PyResult<()> {
+        let py = slf.py();
+        for error in slf.get().layers() {
+            if let Some(exc) = error.exception.as_deref() {
+                return Err(PyErr::from_value(exc.bind(py).clone()));
+            }
+        }
+        let error = slf.clone().into_any().unbind();
+        Err(pyropust_error(py, slf.get().__repr__(), &error))
     }
 
     fn __repr__(&self) -> String {
-        format!(
-            "Error(kind=ErrorKind.{}, code='{}', message='{}')",
+        let mut repr = format!(
+            "Error(kind=ErrorKind.{}, code='{}', message='{}'",
             self.kind.as_str(),
             self.code,
             self.message
-        )
+        );
+        if !self.children.is_empty() {
+            repr.push_str(&format!(", children={{{}}}", self.children_summary()));
+        }
+        repr.push(')');
+        repr
     }
 
     fn __str__(&self) -> String {
         self.__repr__()
     }
 
+    fn __eq__(&self, other: PyRef<'_, Error>) -> bool {
+        self == &*other
+    }
+
+    fn __hash__(&self) -> u64 {
+        stable_hash(self)
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.traverse(&visit)
+    }
+
+    // Pickle as a flat list of `to_dict` layers, one per error of the chain, so deep
+    // chains stay within pickle's recursion limit. Kinds are `ErrorKind` objects so
+    // custom kinds are registered before the layers are read.
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Py<PyAny>,))> {
+        let py = slf.py();
+        let from_layers = py.get_type::<Error>().getattr("_from_layers")?;
+        let layers = PyList::empty(py);
+        for error in slf.get().layers() {
+            let data = error.layer_to_dict(py, false)?;
+            error.set_layer_kind_objects(&data)?;
+            layers.append(data)?;
+        }
+        Ok((from_layers, (layers.into_any().unbind(),)))
+    }
+
+    /// Rebuild a chain from `__reduce__` layers; only used to unpickle errors.
+    #[classmethod]
+    fn _from_layers(_cls: &Bound<'_, PyType>, layers: &Bound<'_, PyList>) -> PyResult<Self> {
+        let layers = layers
+            .iter()
+            .map(|layer| error_from_dict(&layer, false))
+            .collect::<PyResult<Vec<_>>>()?;
+        link_layers(layers).ok_or_else(|| PyValueError::new_err("expected at least one layer"))
+    }
+
+    // Errors are immutable, so copies can share the instance.
+    fn __copy__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: PyRef<'py, Self>, _memo: Bound<'py, PyAny>) -> PyRef<'py, Self> {
+        slf
+    }
+
     #[classmethod]
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (code, message, *, kind = None, op = None, path = None, expected = None, got = None, metadata = None))]
@@ -176,9 +404,44 @@ impl Error {
         )
     }
 
+    /// Aggregate several errors (e.g. every failure of a batch) under one error.
+    #[classmethod]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (code, message, errors, *, kind = None, op = None, path = None, metadata = None))]
+    fn group(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        code: Py<PyAny>,
+        message: String,
+        errors: &Bound<'_, PyAny>,
+        kind: Option<Py<PyAny>>,
+        op: Option<String>,
+        path: Option<Py<PyAny>>,
+        metadata: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        let children = errors
+            .try_iter()?
+            .map(|item| {
+                let item = item?;
+                let child = item
+                    .cast::<Error>()
+                    .map_err(|_| PyTypeError::new_err("group expects Error values"))?;
+                Ok(child.get().clone())
+            })
+            .collect::<PyResult<Vec<Error>>>()?;
+        if children.is_empty() {
+            return Err(PyValueError::new_err("group expects at least one error"));
+        }
+        let mut error = build_error_from_parts(
+            py, code, &message, kind, metadata, op, path, None, None, None,
+        )?;
+        error.children = children;
+        Ok(error)
+    }
+
     #[classmethod]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (err, *, code, message, kind = None, op = None, path = None, expected = None, got = None, metadata = None))]
+    #[pyo3(signature = (err, *, code, message, kind = None, op = None, path = None, expected = None, got = None, metadata = None, keep_exception = false))]
     fn wrap(
         _cls: &Bound<'_, PyType>,
         py: Python<'_>,
@@ -191,42 +454,33 @@ impl Error {
         expected: Option<String>,
         got: Option<String>,
         metadata: Option<Py<PyAny>>,
+        keep_exception: bool,
     ) -> PyResult<Self> {
         let err_ref = err.bind(py);
         if err_ref.is_none() {
             return Err(PyTypeError::new_err("wrap expects an exception or Error"));
         }
 
-        let mut metadata = extract_metadata(py, metadata)?;
+        let metadata = extract_metadata(py, metadata)?;
 
         let cause = if let Ok(cause_ref) = err_ref.extract::<PyRef<'_, Error>>() {
-            Some(cause_ref.__repr__())
+            (*cause_ref).clone()
         } else {
             let base_exc = py.get_type::<PyBaseException>();
             if !err_ref.is_instance(base_exc.as_any())? {
                 return Err(PyTypeError::new_err("wrap expects an exception or Error"));
             }
             let py_err = PyErr::from_value(err_ref.clone());
-            let cause_obj = build_error_from_pyerr(py, py_err, "py_exception");
+            let cause_obj = build_error_from_pyerr(py, py_err, "py_exception", keep_exception);
             let cause_ref = cause_obj.bind(py).extract::<PyRef<'_, Error>>()?;
-            if !metadata.contains_key("cause_exception") {
-                if let Some(value) = cause_ref.metadata.get("exception") {
-                    metadata.insert("cause_exception".to_string(), value.to_string());
-                }
-            }
-            if !metadata.contains_key("cause_py_traceback") {
-                if let Some(value) = cause_ref.metadata.get("py_traceback") {
-                    metadata.insert("cause_py_traceback".to_string(), value.to_string());
-                }
-            }
-            Some(cause_ref.__repr__())
+            (*cause_ref).clone()
         };
 
         let kind = extract_kind(py, kind, ErrorKind::Internal)?;
         let path = extract_path(py, path)?;
         let code = code.bind(py).extract::<String>()?;
 
-        Ok(Error {
+        let mut error = Error {
             kind,
             code,
             message,
@@ -235,11 +489,67 @@ impl Error {
             path,
             expected,
             got,
-            cause,
-        })
+            cause: None,
+            children: Vec::new(),
+            exception: None,
+            tracebacks: Vec::new(),
+        };
+        error.inherit_cause_exception(&cause);
+        error.cause = Some(Arc::new(cause));
+        Ok(error)
+    }
+
+    /// `strict=True` emits metadata as `dict[str, str]`, encoding other values as JSON text.
+    #[pyo3(signature = (*, strict = false))]
+    pub fn to_dict(&self, py: Python<'_>, strict: bool) -> PyResult<Py<PyAny>> {
+        let layers = self
+            .layers()
+            .map(|error| error.layer_to_dict(py, strict))
+            .collect::<PyResult<Vec<_>>>()?;
+        for pair in layers.windows(2) {
+            pair[0].set_item("cause", &pair[1])?;
+        }
+        Ok(layers[0].clone().into_any().unbind())
     }
 
-    fn to_dict(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+    #[classmethod]
+    #[pyo3(signature = (data, *, strict = false))]
+    fn from_dict(
+        _cls: &Bound<'_, PyType>,
+        _py: Python<'_>,
+        data: Bound<'_, PyAny>,
+        strict: bool,
+    ) -> PyResult<Self> {
+        error_from_dict(&data, strict)
+    }
+
+    /// Serialize to a JSON document with a `schema_version` (see docs/errors.md).
+    #[pyo3(signature = (*, strict = false))]
+    fn to_json(&self, strict: bool) -> PyResult<String> {
+        Ok(self.to_json_document(strict)?.to_string())
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (data, *, strict = false))]
+    fn from_json(
+        _cls: &Bound<'_, PyType>,
+        data: &Bound<'_, PyAny>,
+        strict: bool,
+    ) -> PyResult<Self> {
+        let document = parse_json_document(data, "error")?;
+        error_from_json_document(&document, strict)
+            .map_err(|msg| PyValueError::new_err(format!("invalid error JSON: {msg}")))
+    }
+}
+
+impl Error {
+    /// This error followed by each cause, outermost first.
+    pub fn layers(&self) -> impl Iterator<Item = &Error> {
+        std::iter::successors(Some(self), |error| error.cause.as_deref())
+    }
+
+    /// This error's `to_dict` fields with `cause` set to `None`.
+    fn layer_to_dict<'py>(&self, py: Python<'py>, strict: bool) -> PyResult<Bound<'py, PyDict>> {
         let dict = PyDict::new(py);
         dict.set_item("kind", self.kind.as_str())?;
         dict.set_item("code", self.code.clone())?;
@@ -257,101 +567,371 @@ impl Error {
 
         dict.set_item("expected", self.expected.clone())?;
         dict.set_item("got", self.got.clone())?;
-        dict.set_item("cause", self.cause.clone())?;
+        dict.set_item("cause", py.None())?;
+        let children = self
+            .children
+            .iter()
+            .map(|child| child.to_dict(py, strict))
+            .collect::<PyResult<Vec<_>>>()?;
+        dict.set_item("children", children)?;
+
+        dict.set_item(
+            "metadata",
+            metadata_to_dict(py, &self.resolved_metadata(), strict)?,
+        )?;
+        Ok(dict)
+    }
 
-        let metadata_dict = PyDict::new(py);
-        for (k, v) in &self.metadata {
-            metadata_dict.set_item(k, v)?;
+    /// `metadata` with captured tracebacks formatted in; explicit entries take precedence.
+    pub fn resolved_metadata(&self) -> Cow<'_, Metadata> {
+        if self.tracebacks.is_empty() {
+            return Cow::Borrowed(&self.metadata);
         }
-        dict.set_item("metadata", metadata_dict)?;
+        Python::attach(|py| {
+            let mut metadata = self.metadata.clone();
+            for (key, traceback) in &self.tracebacks {
+                if metadata.contains_key(*key) {
+                    continue;
+                }
+                if let Some(text) = traceback.text(py) {
+                    metadata.insert(key.to_string(), Value::String(text));
+                }
+            }
+            Cow::Owned(metadata)
+        })
+    }
 
-        Ok(dict.into())
+    /// Record a converted cause's exception type and traceback as `cause_exception` /
+    /// `cause_py_traceback` unless already set. The traceback stays unformatted.
+    pub fn inherit_cause_exception(&mut self, cause: &Error) {
+        if !self.metadata.contains_key("cause_exception") {
+            if let Some(value) = cause.metadata.get("exception") {
+                self.metadata
+                    .insert("cause_exception".to_string(), value.clone());
+            }
+        }
+        if self.metadata.contains_key("cause_py_traceback") {
+            return;
+        }
+        if let Some(value) = cause.metadata.get("py_traceback") {
+            self.metadata
+                .insert("cause_py_traceback".to_string(), value.clone());
+        } else if let Some((_, traceback)) = cause
+            .tracebacks
+            .iter()
+            .find(|(key, _)| *key == "py_traceback")
+        {
+            self.tracebacks
+                .push(("cause_py_traceback", traceback.clone()));
+        }
     }
 
-    #[classmethod]
-    fn from_dict(
-        _cls: &Bound<'_, PyType>,
-        _py: Python<'_>,
-        data: Bound<'_, PyAny>,
-    ) -> PyResult<Self> {
-        let dict = data.cast_exact::<PyDict>()?;
+    /// Report the Python objects owned by this error and its causes and children.
+    /// `Error` clones share these through `Arc`s, and the GC must only see
+    /// references the object holds itself, so an `Arc` is reported once and only
+    /// when all of its owners are inside this error.
+    fn traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        let mut exceptions = Vec::new();
+        let mut tracebacks = Vec::new();
+        self.collect_shared(&mut exceptions, &mut tracebacks);
+        for exception in owned_once(&exceptions) {
+            visit.call(exception.as_ref())?;
+        }
+        for traceback in owned_once(&tracebacks) {
+            traceback.traverse(visit)?;
+        }
+        Ok(())
+    }
 
-        let kind_value = dict
-            .get_item("kind")?
-            .ok_or_else(|| PyTypeError::new_err("missing 'kind' field"))?;
-        let kind = if let Ok(kind_obj) = kind_value.extract::<PyRef<'_, ErrorKindObj>>() {
-            kind_obj.kind
-        } else {
-            let kind_str = kind_value.extract::<String>()?;
-            match kind_str.as_str() {
-                "InvalidInput" => ErrorKind::InvalidInput,
-                "NotFound" => ErrorKind::NotFound,
-                "Internal" => ErrorKind::Internal,
-                _ => {
-                    return Err(PyTypeError::new_err(
-                        "invalid 'kind' field (expected ErrorKind or string)",
-                    ))
-                }
+    /// A cause shared with other errors is skipped: those errors may be alive, so
+    /// reporting its contents from here could free objects they still use.
+    fn collect_shared<'a>(
+        &'a self,
+        exceptions: &mut Vec<&'a Arc<Py<PyAny>>>,
+        tracebacks: &mut Vec<&'a Arc<LazyTraceback>>,
+    ) {
+        let mut pending = vec![self];
+        while let Some(error) = pending.pop() {
+            exceptions.extend(&error.exception);
+            tracebacks.extend(error.tracebacks.iter().map(|(_, traceback)| traceback));
+            let owned_cause = error
+                .cause
+                .as_ref()
+                .filter(|cause| Arc::strong_count(cause) == 1);
+            pending.extend(owned_cause.map(|cause| &**cause));
+            pending.extend(&error.children);
+        }
+    }
+
+    /// Replace the `kind` names in `data` (this error's `to_dict`) with `ErrorKind`s.
+    fn set_kind_objects(&self, data: &Bound<'_, PyAny>) -> PyResult<()> {
+        let mut data = data.clone();
+        for (index, error) in self.layers().enumerate() {
+            if index > 0 {
+                data = data.get_item("cause")?;
             }
-        };
+            error.set_layer_kind_objects(&data)?;
+        }
+        Ok(())
+    }
 
-        let code = dict
-            .get_item("code")?
-            .ok_or_else(|| PyTypeError::new_err("missing 'code' field"))?
-            .extract::<String>()?;
-        let message = dict
-            .get_item("message")?
-            .ok_or_else(|| PyTypeError::new_err("missing 'message' field"))?
-            .extract::<String>()?;
-
-        let op = get_optional_string(dict, "op")?;
-        let expected = get_optional_string(dict, "expected")?;
-        let got = get_optional_string(dict, "got")?;
-        let cause = get_optional_string(dict, "cause")?;
-
-        let mut path = Vec::new();
-        if let Some(path_value) = dict.get_item("path")? {
-            let list = path_value.cast_exact::<PyList>()?;
-            for item in list.iter() {
-                if let Ok(key) = item.extract::<String>() {
-                    path.push(PathItem::Key(key));
-                } else if let Ok(index) = item.extract::<usize>() {
-                    path.push(PathItem::Index(index));
-                } else {
-                    return Err(PyTypeError::new_err(
-                        "invalid path element (expected str or int)",
-                    ));
-                }
+    /// `set_kind_objects` for this error's `layer_to_dict`.
+    fn set_layer_kind_objects(&self, data: &Bound<'_, PyAny>) -> PyResult<()> {
+        data.set_item("kind", ErrorKindObj { kind: self.kind })?;
+        let children = data.get_item("children")?;
+        for (child, child_data) in self.children.iter().zip(children.try_iter()?) {
+            child.set_kind_objects(&child_data?)?;
+        }
+        Ok(())
+    }
+
+    /// `'code': count` pairs for the direct children, in order of first appearance.
+    fn children_summary(&self) -> String {
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+        for child in &self.children {
+            match counts.iter_mut().find(|(code, _)| *code == child.code) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((&child.code, 1)),
             }
         }
+        counts
+            .iter()
+            .map(|(code, count)| format!("'{code}': {count}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+pub fn error_from_dict(data: &Bound<'_, PyAny>, strict: bool) -> PyResult<Error> {
+    let mut layers = Vec::new();
+    let mut next = Some(data.clone());
+    while let Some(data) = next {
+        let (error, cause) = layer_from_dict(&data, strict)?;
+        layers.push(error);
+        next = cause;
+    }
+    Ok(link_layers(layers).expect("at least one layer"))
+}
+
+/// One error of a `to_dict` chain, plus its `cause` entry when it is a dict.
+fn layer_from_dict<'py>(
+    data: &Bound<'py, PyAny>,
+    strict: bool,
+) -> PyResult<(Error, Option<Bound<'py, PyAny>>)> {
+    let dict = data.cast_exact::<PyDict>()?;
+
+    let kind_value = dict
+        .get_item("kind")?
+        .ok_or_else(|| PyTypeError::new_err("missing 'kind' field"))?;
+    let kind = parse_kind(&kind_value)?;
+
+    let code = dict
+        .get_item("code")?
+        .ok_or_else(|| PyTypeError::new_err("missing 'code' field"))?
+        .extract::<String>()?;
+    let message = dict
+        .get_item("message")?
+        .ok_or_else(|| PyTypeError::new_err("missing 'message' field"))?
+        .extract::<String>()?;
 
-        let mut metadata = HashMap::new();
-        if let Some(meta_value) = dict.get_item("metadata")? {
-            let meta_dict = meta_value.cast_exact::<PyDict>()?;
-            for (k, v) in meta_dict.iter() {
-                let key = k.extract::<String>()?;
-                let value = v.extract::<String>()?;
-                metadata.insert(key, value);
+    let op = get_optional_string(dict, "op")?;
+    let expected = get_optional_string(dict, "expected")?;
+    let got = get_optional_string(dict, "got")?;
+    // Nested causes are dicts; a plain string is the pre-chain format and becomes a
+    // message-only error.
+    let (cause, cause_data) = match dict.get_item("cause")? {
+        Some(value) if value.is_none() => (None, None),
+        Some(value) => match value.extract::<String>() {
+            Ok(message) => (Some(Arc::new(message_cause(message))), None),
+            Err(_) => (None, Some(value)),
+        },
+        None => (None, None),
+    };
+    let children = match dict.get_item("children")? {
+        Some(value) => value
+            .cast_exact::<PyList>()?
+            .iter()
+            .map(|child| error_from_dict(&child, strict))
+            .collect::<PyResult<Vec<_>>>()?,
+        None => Vec::new(),
+    };
+
+    let mut path = Vec::new();
+    if let Some(path_value) = dict.get_item("path")? {
+        let list = path_value.cast_exact::<PyList>()?;
+        for item in list.iter() {
+            if let Ok(key) = item.extract::<String>() {
+                path.push(PathItem::Key(key));
+            } else if let Ok(index) = item.extract::<usize>() {
+                path.push(PathItem::Index(index));
+            } else {
+                return Err(PyTypeError::new_err(
+                    "invalid path element (expected str or int)",
+                ));
             }
         }
+    }
 
-        Ok(Error {
-            kind,
-            code,
-            message,
-            metadata,
-            op,
-            path,
-            expected,
-            got,
-            cause,
-        })
+    let metadata = match dict.get_item("metadata")? {
+        Some(meta_value) => metadata_from_dict(meta_value.cast_exact::<PyDict>()?, strict)?,
+        None => Metadata::new(),
+    };
+
+    let error = Error {
+        kind,
+        code,
+        message,
+        metadata,
+        op,
+        path,
+        expected,
+        got,
+        cause,
+        children,
+        exception: None,
+        tracebacks: Vec::new(),
+    };
+    Ok((error, cause_data))
+}
+
+/// Chain `layers` (outermost first) through their causes, keeping the innermost
+/// layer's own cause.
+fn link_layers(layers: Vec<Error>) -> Option<Error> {
+    let mut layers = layers.into_iter().rev();
+    let mut error = layers.next()?;
+    for mut outer in layers {
+        outer.cause = Some(Arc::new(error));
+        error = outer;
+    }
+    Some(error)
+}
+
+fn message_cause(message: String) -> Error {
+    Error {
+        kind: ErrorKind::Internal,
+        code: "cause".to_string(),
+        message,
+        metadata: Metadata::new(),
+        op: None,
+        path: Vec::new(),
+        expected: None,
+        got: None,
+        cause: None,
+        children: Vec::new(),
+        exception: None,
+        tracebacks: Vec::new(),
+    }
+}
+
+/// Accept an `Error` or a plain message for the `cause=` keyword.
+pub fn extract_cause(py: Python<'_>, cause: Option<Py<PyAny>>) -> PyResult<Option<Arc<Error>>> {
+    let Some(cause) = cause else {
+        return Ok(None);
+    };
+    let cause = cause.bind(py);
+    if let Ok(error) = cause.extract::<PyRef<'_, Error>>() {
+        return Ok(Some(Arc::new((*error).clone())));
+    }
+    if let Ok(message) = cause.extract::<String>() {
+        return Ok(Some(Arc::new(message_cause(message))));
+    }
+    Err(PyTypeError::new_err("cause must be an Error or str"))
+}
+
+// Causes are walked in a loop rather than through the derived recursion, so long
+// chains do not overflow the stack.
+impl Drop for Error {
+    fn drop(&mut self) {
+        let mut next = self.cause.take();
+        while let Some(cause) = next {
+            next = Arc::into_inner(cause).and_then(|mut error| error.cause.take());
+        }
+    }
+}
+
+/// Metadata filled from captured tracebacks. Like `frames` and the retained exception
+/// they are runtime context, left out of equality and hashing so comparing errors
+/// never formats a traceback.
+const TRACEBACK_KEYS: [&str; 2] = ["py_traceback", "cause_py_traceback"];
+
+// The retained exception is a runtime attachment, so equality only covers the data.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        let mut layers = self.layers();
+        let mut other_layers = other.layers();
+        loop {
+            match (layers.next(), other_layers.next()) {
+                (Some(error), Some(other)) if std::ptr::eq(error, other) => return true,
+                (Some(error), Some(other)) if error.layer_eq(other) => {}
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
     }
 }
 
+impl Eq for Error {}
+
+impl Hash for Error {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for error in self.layers() {
+            error.kind.hash(state);
+            error.code.hash(state);
+            error.message.hash(state);
+            hash_metadata(&error.metadata, &TRACEBACK_KEYS, state);
+            error.op.hash(state);
+            error.path.hash(state);
+            error.expected.hash(state);
+            error.got.hash(state);
+            error.children.hash(state);
+        }
+    }
+}
+
+impl Error {
+    /// `==` for everything but the cause.
+    fn layer_eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.code == other.code
+            && self.message == other.message
+            && metadata_eq(&self.metadata, &other.metadata, &TRACEBACK_KEYS)
+            && self.op == other.op
+            && self.path == other.path
+            && self.expected == other.expected
+            && self.got == other.got
+            && self.children == other.children
+    }
+}
+
+/// The distinct `Arc`s in `arcs` whose every strong reference is in `arcs`.
+fn owned_once<'a, T>(arcs: &[&'a Arc<T>]) -> Vec<&'a Arc<T>> {
+    let mut owners: HashMap<*const T, usize> = HashMap::new();
+    for arc in arcs {
+        *owners.entry(Arc::as_ptr(arc)).or_default() += 1;
+    }
+    arcs.iter()
+        .filter(|arc| {
+            // Removing the entry reports each `Arc` at its first occurrence only.
+            owners.remove(&Arc::as_ptr(arc)) == Some(Arc::strong_count(arc))
+        })
+        .copied()
+        .collect()
+}
+
+fn stable_hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
 #[pyfunction]
-#[pyo3(signature = (exc, code = "py_exception"))]
-pub fn exception_to_error(py: Python<'_>, exc: Py<PyAny>, code: &str) -> PyResult<Py<Error>> {
+#[pyo3(signature = (exc, code = "py_exception", *, keep_exception = false))]
+pub fn exception_to_error(
+    py: Python<'_>,
+    exc: Py<PyAny>,
+    code: &str,
+    keep_exception: bool,
+) -> PyResult<Py<Error>> {
     let exc_ref = exc.bind(py);
     let base_exc = py.get_type::<PyBaseException>();
     if !exc_ref.is_instance(base_exc.as_any())? {
@@ -360,37 +940,145 @@ pub fn exception_to_error(py: Python<'_>, exc: Py<PyAny>, code: &str) -> PyResul
         ));
     }
     let py_err = PyErr::from_value(exc_ref.clone());
-    Ok(build_error_from_pyerr(py, py_err, code))
+    Ok(build_error_from_pyerr(py, py_err, code, keep_exception))
+}
+
+pub fn build_error_from_pyerr(
+    py: Python<'_>,
+    py_err: PyErr,
+    code: &str,
+    keep_exception: bool,
+) -> Py<Error> {
+    let exc = py_err.into_value(py).into_bound(py);
+    let error = error_from_exception_chain(&exc, code, keep_exception, true, &mut Vec::new());
+    Py::new(py, error).expect("error alloc")
+}
+
+/// Deepest exception nesting (chain links plus group levels) converted. Errors are
+/// compared, hashed and serialized recursively, so deeper links are dropped and the
+/// last converted error is marked with `exception_chain_truncated`.
+const MAX_EXCEPTION_DEPTH: usize = 100;
+
+/// Convert `exc` and, recursively, its `__cause__` / `__context__` into an error chain.
+/// Only the outermost error carries the full chained traceback text.
+fn error_from_exception_chain(
+    exc: &Bound<'_, PyBaseException>,
+    code: &str,
+    keep_exception: bool,
+    full_traceback: bool,
+    seen: &mut Vec<*mut ffi::PyObject>,
+) -> Error {
+    // `seen` holds the exceptions on the current path, so cyclic contexts terminate.
+    seen.push(exc.as_ptr());
+    let py_err = PyErr::from_value(exc.clone().into_any());
+
+    let mut metadata = Metadata::new();
+    if let Ok(name) = exc.get_type().name() {
+        metadata.insert("exception".to_string(), Value::String(name.to_string()));
+    }
+    if let Some(notes) = exception_notes(exc) {
+        metadata.insert("notes".to_string(), Value::Array(notes));
+    }
+    let inner_exceptions = group_exceptions(exc);
+    let chained = chained_exception(exc);
+    let truncated = seen.len() >= MAX_EXCEPTION_DEPTH;
+    if truncated && (chained.is_some() || !inner_exceptions.is_empty()) {
+        metadata.insert("exception_chain_truncated".to_string(), Value::Bool(true));
+    }
+    let children = if truncated {
+        Vec::new()
+    } else {
+        inner_exceptions
+            .into_iter()
+            .map(|inner| {
+                error_from_exception_chain(&inner, "py_exception", keep_exception, false, seen)
+            })
+            .collect()
+    };
+    let cause = match chained {
+        Some((relation, inner)) if !truncated && !seen.contains(&inner.as_ptr()) => {
+            metadata.insert(
+                "exception_chain".to_string(),
+                Value::String(relation.to_string()),
+            );
+            Some(Arc::new(error_from_exception_chain(
+                &inner,
+                "py_exception",
+                keep_exception,
+                false,
+                seen,
+            )))
+        }
+        _ => None,
+    };
+    seen.pop();
+
+    Error {
+        kind: ErrorKind::Internal,
+        code: code.to_string(),
+        message: py_err.to_string(),
+        metadata,
+        op: None,
+        path: Vec::new(),
+        expected: None,
+        got: None,
+        cause,
+        children,
+        exception: keep_exception.then(|| Arc::new(exc.clone().into_any().unbind())),
+        tracebacks: LazyTraceback::capture(exc, full_traceback)
+            .map(|traceback| ("py_traceback", Arc::new(traceback)))
+            .into_iter()
+            .collect(),
+    }
 }
 
-pub fn build_error_from_pyerr(py: Python<'_>, py_err: PyErr, code: &str) -> Py<Error> {
-    let mut metadata = HashMap::new();
-    if let Ok(name) = py_err.get_type(py).name() {
-        metadata.insert("exception".to_string(), name.to_string());
+/// The chained exception the traceback module would print: `__cause__` (`raise ... from`),
+/// else `__context__` unless suppressed.
+fn chained_exception<'py>(
+    exc: &Bound<'py, PyBaseException>,
+) -> Option<(&'static str, Bound<'py, PyBaseException>)> {
+    if let Ok(cause) = exc
+        .getattr("__cause__")
+        .ok()?
+        .cast_into::<PyBaseException>()
+    {
+        return Some(("cause", cause));
     }
-    if let Some(traceback) = format_traceback(py, &py_err) {
-        metadata.insert("py_traceback".to_string(), traceback);
+    if exc.getattr("__suppress_context__").ok()?.is_truthy().ok()? {
+        return None;
     }
-    let cause = py_err
-        .value(py)
-        .repr()
+    let context = exc.getattr("__context__").ok()?;
+    context
+        .cast_into::<PyBaseException>()
         .ok()
-        .and_then(|s| s.to_str().ok().map(|v| v.to_string()));
-    Py::new(
-        py,
-        Error {
-            kind: ErrorKind::Internal,
-            code: code.to_string(),
-            message: py_err.to_string(),
-            metadata,
-            op: None,
-            path: Vec::new(),
-            expected: None,
-            got: None,
-            cause,
-        },
-    )
-    .expect("error alloc")
+        .map(|context| ("context", context))
+}
+
+/// The direct sub-exceptions of a `BaseExceptionGroup` (empty for other exceptions).
+fn group_exceptions<'py>(exc: &Bound<'py, PyBaseException>) -> Vec<Bound<'py, PyBaseException>> {
+    if !exc.is_instance_of::<PyBaseExceptionGroup>() {
+        return Vec::new();
+    }
+    let Ok(exceptions) = exc.getattr("exceptions") else {
+        return Vec::new();
+    };
+    let Ok(exceptions) = exceptions.try_iter() else {
+        return Vec::new();
+    };
+    exceptions
+        .filter_map(|inner| inner.ok()?.cast_into::<PyBaseException>().ok())
+        .collect()
+}
+
+/// PEP 678 notes, as strings.
+fn exception_notes(exc: &Bound<'_, PyBaseException>) -> Option<Vec<Value>> {
+    let notes = exc.getattr("__notes__").ok()?;
+    let notes = notes
+        .try_iter()
+        .ok()?
+        .filter_map(|note| Some(Value::String(note.ok()?.str().ok()?.to_string())))
+        .collect();
+    Some(notes)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -404,9 +1092,10 @@ pub fn build_error_from_parts(
     path: Option<Py<PyAny>>,
     expected: Option<String>,
     got: Option<String>,
-    cause: Option<String>,
+    cause: Option<Py<PyAny>>,
 ) -> PyResult<Error> {
     let kind = extract_kind(py, kind, ErrorKind::InvalidInput)?;
+    let cause = extract_cause(py, cause)?;
     let path = extract_path(py, path)?;
     let metadata = extract_metadata(py, metadata)?;
     let code = code.bind(py).extract::<String>()?;
@@ -421,23 +1110,12 @@ pub fn build_error_from_parts(
         expected,
         got,
         cause,
+        children: Vec::new(),
+        exception: None,
+        tracebacks: Vec::new(),
     })
 }
 
-fn format_traceback(py: Python<'_>, py_err: &PyErr) -> Option<String> {
-    let traceback_mod = py.import("traceback").ok()?;
-    let tb = py_err.traceback(py);
-    let formatted = traceback_mod
-        .call_method1(
-            "format_exception",
-            (py_err.get_type(py), py_err.value(py), tb),
-        )
-        .ok()?
-        .extract::<Vec<String>>()
-        .ok()?;
-    Some(formatted.concat())
-}
-
 fn extract_kind(
     py: Python<'_>,
     kind: Option<Py<PyAny>>,
@@ -446,19 +1124,20 @@ fn extract_kind(
     let Some(kind_value) = kind else {
         return Ok(default_kind);
     };
-    let kind_value = kind_value.bind(py);
-    if let Ok(kind_obj) = kind_value.extract::<PyRef<'_, ErrorKindObj>>() {
+    parse_kind(kind_value.bind(py))
+}
+
+/// Accept an `ErrorKind` or the name of a built-in or registered kind.
+fn parse_kind(value: &Bound<'_, PyAny>) -> PyResult<ErrorKind> {
+    if let Ok(kind_obj) = value.extract::<PyRef<'_, ErrorKindObj>>() {
         return Ok(kind_obj.kind);
     }
-    let kind_str = kind_value.extract::<String>()?;
-    match kind_str.as_str() {
-        "InvalidInput" => Ok(ErrorKind::InvalidInput),
-        "NotFound" => Ok(ErrorKind::NotFound),
-        "Internal" => Ok(ErrorKind::Internal),
-        _ => Err(PyTypeError::new_err(
-            "invalid 'kind' field (expected ErrorKind or string)",
-        )),
-    }
+    let name = value
+        .extract::<String>()
+        .map_err(|_| PyTypeError::new_err("invalid 'kind' field (expected ErrorKind or string)"))?;
+    name.parse::<ErrorKind>().map_err(|_| {
+        PyTypeError::new_err(format!("invalid 'kind' field (unknown ErrorKind '{name}')"))
+    })
 }
 
 fn extract_path(py: Python<'_>, path: Option<Py<PyAny>>) -> PyResult<Vec<PathItem>> {
@@ -482,23 +1161,6 @@ fn extract_path(py: Python<'_>, path: Option<Py<PyAny>>) -> PyResult<Vec<PathIte
     Ok(path)
 }
 
-fn extract_metadata(
-    py: Python<'_>,
-    metadata: Option<Py<PyAny>>,
-) -> PyResult<HashMap<String, String>> {
-    let mut data = HashMap::new();
-    let Some(meta_value) = metadata else {
-        return Ok(data);
-    };
-    let meta_dict = meta_value.bind(py).cast_exact::<PyDict>()?;
-    for (k, v) in meta_dict.iter() {
-        let key = k.extract::<String>()?;
-        let value = v.extract::<String>()?;
-        data.insert(key, value);
-    }
-    Ok(data)
-}
-
 fn get_optional_string(dict: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<String>> {
     if let Some(value) = dict.get_item(key)? {
         value.extract::<Option<String>>()
@@ -0,0 +1,434 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyAny, PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use serde_json::{Map, Number, Value};
+use std::sync::Arc;
+
+use super::error::{Error, ErrorKind, PathItem};
+use super::metadata::Metadata;
+
+/// Version of the `Error.to_json` document; bumped on incompatible format changes.
+pub const SCHEMA_VERSION: u64 = 1;
+
+const FIELDS: [&str; 10] = [
+    "kind", "code", "message", "op", "path", "expected", "got", "cause", "children", "metadata",
+];
+
+/// Deepest container nesting of a whole document, the most `serde_json` parses back.
+const MAX_DOCUMENT_DEPTH: usize = 127;
+
+impl Error {
+    /// The top-level JSON document: the error object plus `schema_version`.
+    pub fn to_json_document(&self, strict: bool) -> PyResult<Value> {
+        let mut object = self.to_json_object(strict, 1)?;
+        object.insert("schema_version".to_string(), Value::from(SCHEMA_VERSION));
+        let document = Value::Object(object);
+        check_document_depth(&document)?;
+        Ok(document)
+    }
+
+    /// The error as a JSON object without `schema_version` (nested causes, `Result` envelopes).
+    /// `depth` is the object's nesting level in the document, 1 at the top.
+    pub fn to_json_object(&self, strict: bool, depth: usize) -> PyResult<Map<String, Value>> {
+        if depth > MAX_DOCUMENT_DEPTH {
+            return Err(too_deep());
+        }
+        let path = self
+            .path
+            .iter()
+            .map(|item| match item {
+                PathItem::Key(key) => Value::String(key.clone()),
+                PathItem::Index(index) => Value::from(*index),
+            })
+            .collect();
+        let metadata = self
+            .resolved_metadata()
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    Value::String(_) => value.clone(),
+                    _ if strict => Value::String(value.to_string()),
+                    _ => value.clone(),
+                };
+                (key.clone(), value)
+            })
+            .collect();
+        let cause = match self.cause.as_deref() {
+            Some(cause) => Value::Object(cause.to_json_object(strict, depth + 1)?),
+            None => Value::Null,
+        };
+
+        let mut object = Map::new();
+        object.insert("kind".to_string(), Value::from(self.kind.as_str()));
+        object.insert("code".to_string(), Value::from(self.code.clone()));
+        object.insert("message".to_string(), Value::from(self.message.clone()));
+        object.insert("op".to_string(), Value::from(self.op.clone()));
+        object.insert("path".to_string(), Value::Array(path));
+        object.insert("expected".to_string(), Value::from(self.expected.clone()));
+        object.insert("got".to_string(), Value::from(self.got.clone()));
+        object.insert("cause".to_string(), cause);
+        let children = self
+            .children
+            .iter()
+            .map(|child| Ok(Value::Object(child.to_json_object(strict, depth + 2)?)))
+            .collect::<PyResult<_>>()?;
+        object.insert("children".to_string(), Value::Array(children));
+        object.insert("metadata".to_string(), Value::Object(metadata));
+        Ok(object)
+    }
+}
+
+/// Reject documents `from_json` could not read back: `serde_json` stops parsing at
+/// its recursion limit.
+fn check_document_depth(document: &Value) -> PyResult<()> {
+    if json_depth(document) > MAX_DOCUMENT_DEPTH {
+        return Err(too_deep());
+    }
+    Ok(())
+}
+
+fn json_depth(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => 1 + items.iter().map(json_depth).max().unwrap_or(0),
+        Value::Object(map) => 1 + map.values().map(json_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn too_deep() -> PyErr {
+    PyValueError::new_err(format!(
+        "JSON documents must not be nested more than {MAX_DOCUMENT_DEPTH} levels deep"
+    ))
+}
+
+/// Parse a `str` / `bytes` JSON document; `what` names the format in error messages.
+pub fn parse_json_document(data: &Bound<'_, PyAny>, what: &str) -> PyResult<Value> {
+    let parsed = if let Ok(text) = data.cast::<PyString>() {
+        serde_json::from_str::<Value>(text.to_str()?)
+    } else if let Ok(bytes) = data.cast::<PyBytes>() {
+        serde_json::from_slice::<Value>(bytes.as_bytes())
+    } else {
+        return Err(PyTypeError::new_err("from_json expects str or bytes"));
+    };
+    parsed.map_err(|e| PyValueError::new_err(format!("invalid {what} JSON: {e}")))
+}
+
+fn check_schema_version(object: &Map<String, Value>) -> Result<(), String> {
+    match object.get("schema_version") {
+        None => Err("missing 'schema_version'".to_string()),
+        Some(version) if version.as_u64() == Some(SCHEMA_VERSION) => Ok(()),
+        Some(Value::Number(version)) => Err(format!(
+            "unsupported schema_version {version} (expected {SCHEMA_VERSION})"
+        )),
+        Some(_) => Err("'schema_version' must be an integer".to_string()),
+    }
+}
+
+/// Validate a decoded `Error.to_json` document. Errors name the offending field.
+pub fn error_from_json_document(document: &Value, strict: bool) -> Result<Error, String> {
+    let object = document
+        .as_object()
+        .ok_or_else(|| "expected a JSON object".to_string())?;
+    check_schema_version(object)?;
+    error_from_json_object(object, "", strict)
+}
+
+/// A `Result` / `Option` JSON document: `schema_version` plus exactly one variant key.
+pub fn envelope_document(variant: &str, payload: Value) -> PyResult<Value> {
+    let mut object = Map::new();
+    object.insert("schema_version".to_string(), Value::from(SCHEMA_VERSION));
+    object.insert(variant.to_string(), payload);
+    let document = Value::Object(object);
+    check_document_depth(&document)?;
+    Ok(document)
+}
+
+/// Split a decoded envelope into its variant key (one of `variants`) and payload.
+pub fn envelope_variant<'a>(
+    document: &'a Value,
+    variants: [&'static str; 2],
+) -> Result<(&'static str, &'a Value), String> {
+    let object = document
+        .as_object()
+        .ok_or_else(|| "expected a JSON object".to_string())?;
+    check_schema_version(object)?;
+    for key in object.keys() {
+        if key != "schema_version" && !variants.contains(&key.as_str()) {
+            return Err(format!("unknown field '{key}'"));
+        }
+    }
+    match variants.map(|variant| object.get(variant)) {
+        [Some(payload), None] => Ok((variants[0], payload)),
+        [None, Some(payload)] => Ok((variants[1], payload)),
+        _ => Err(format!(
+            "expected exactly one of '{}' or '{}'",
+            variants[0], variants[1]
+        )),
+    }
+}
+
+/// Apply an optional `encoder` hook before converting a payload to JSON.
+pub fn encode_payload(
+    value: &Bound<'_, PyAny>,
+    encoder: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Value> {
+    match encoder {
+        Some(encoder) => to_json_value(&encoder.call1((value,))?, 1),
+        None => to_json_value(value, 1),
+    }
+}
+
+/// Convert a JSON payload back to Python, then apply an optional `decoder` hook.
+pub fn decode_payload<'py>(
+    py: Python<'py>,
+    value: &Value,
+    decoder: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let value = from_json_value(py, value)?;
+    match decoder {
+        Some(decoder) => decoder.call1((value,)),
+        None => Ok(value),
+    }
+}
+
+// Nested causes use the same object without `schema_version`; `prefix` locates them ("cause.").
+pub fn error_from_json_object(
+    object: &Map<String, Value>,
+    prefix: &str,
+    strict: bool,
+) -> Result<Error, String> {
+    for key in object.keys() {
+        let known =
+            FIELDS.contains(&key.as_str()) || (prefix.is_empty() && key == "schema_version");
+        if !known {
+            return Err(format!("unknown field '{prefix}{key}'"));
+        }
+    }
+
+    let kind_name = required_string(object, prefix, "kind")?;
+    let kind = kind_name
+        .parse::<ErrorKind>()
+        .map_err(|_| format!("'{prefix}kind' is not a known ErrorKind: '{kind_name}'"))?;
+    let code = required_string(object, prefix, "code")?;
+    let message = required_string(object, prefix, "message")?;
+    let op = optional_string(object, prefix, "op")?;
+    let expected = optional_string(object, prefix, "expected")?;
+    let got = optional_string(object, prefix, "got")?;
+
+    let mut path = Vec::new();
+    match object.get("path") {
+        None => {}
+        Some(Value::Array(items)) => {
+            for (index, item) in items.iter().enumerate() {
+                let item = match item {
+                    Value::String(key) => PathItem::Key(key.clone()),
+                    Value::Number(number) => number
+                        .as_u64()
+                        .and_then(|value| usize::try_from(value).ok())
+                        .map(PathItem::Index)
+                        .ok_or_else(|| invalid_path_item(prefix, index))?,
+                    _ => return Err(invalid_path_item(prefix, index)),
+                };
+                path.push(item);
+            }
+        }
+        Some(_) => return Err(format!("'{prefix}path' must be an array")),
+    }
+
+    let mut metadata = Metadata::new();
+    match object.get("metadata") {
+        None => {}
+        Some(Value::Object(entries)) => {
+            for (key, value) in entries {
+                if strict && !value.is_string() {
+                    return Err(format!("'{prefix}metadata.{key}' must be a string"));
+                }
+                metadata.insert(key.clone(), value.clone());
+            }
+        }
+        Some(_) => return Err(format!("'{prefix}metadata' must be an object")),
+    }
+
+    let cause = match object.get("cause") {
+        None | Some(Value::Null) => None,
+        Some(Value::Object(cause)) => Some(Arc::new(error_from_json_object(
+            cause,
+            &format!("{prefix}cause."),
+            strict,
+        )?)),
+        Some(_) => return Err(format!("'{prefix}cause' must be an object or null")),
+    };
+
+    let mut children = Vec::new();
+    match object.get("children") {
+        None => {}
+        Some(Value::Array(items)) => {
+            for (index, item) in items.iter().enumerate() {
+                let child_prefix = format!("{prefix}children[{index}].");
+                let Value::Object(child) = item else {
+                    return Err(format!("'{prefix}children[{index}]' must be an object"));
+                };
+                children.push(error_from_json_object(child, &child_prefix, strict)?);
+            }
+        }
+        Some(_) => return Err(format!("'{prefix}children' must be an array")),
+    }
+
+    Ok(Error {
+        kind,
+        code,
+        message,
+        metadata,
+        op,
+        path,
+        expected,
+        got,
+        cause,
+        children,
+        exception: None,
+        tracebacks: Vec::new(),
+    })
+}
+
+fn required_string(object: &Map<String, Value>, prefix: &str, key: &str) -> Result<String, String> {
+    match object.get(key) {
+        Some(Value::String(value)) => Ok(value.clone()),
+        Some(_) => Err(format!("'{prefix}{key}' must be a string")),
+        None => Err(format!("missing '{prefix}{key}'")),
+    }
+}
+
+fn optional_string(
+    object: &Map<String, Value>,
+    prefix: &str,
+    key: &str,
+) -> Result<Option<String>, String> {
+    match object.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(format!("'{prefix}{key}' must be a string or null")),
+    }
+}
+
+fn invalid_path_item(prefix: &str, index: usize) -> String {
+    format!("'{prefix}path[{index}]' must be a string or a non-negative integer")
+}
+
+/// Convert a JSON-compatible Python value (None, bool, int, float, str, list, tuple, dict).
+/// `enclosing` is the number of document levels around the value (1 for a `Result` /
+/// `Option` payload); they count against the nesting allowed in the value.
+pub fn to_json_value(value: &Bound<'_, PyAny>, enclosing: usize) -> PyResult<Value> {
+    let limit = MAX_DOCUMENT_DEPTH - enclosing;
+    convert_json_value(value, &mut Vec::new(), limit)
+}
+
+/// `containers` holds the ids of the lists, tuples and dicts being converted, outermost
+/// first, to detect cycles and stop at `limit` levels.
+fn convert_json_value(
+    value: &Bound<'_, PyAny>,
+    containers: &mut Vec<usize>,
+    limit: usize,
+) -> PyResult<Value> {
+    if value.is_none() {
+        return Ok(Value::Null);
+    }
+    // bool is a subclass of int, so it has to be checked first.
+    if let Ok(flag) = value.cast::<PyBool>() {
+        return Ok(Value::Bool(flag.is_true()));
+    }
+    if value.is_instance_of::<PyInt>() {
+        if let Ok(number) = value.extract::<i64>() {
+            return Ok(Value::from(number));
+        }
+        if let Ok(number) = value.extract::<u64>() {
+            return Ok(Value::from(number));
+        }
+        return Err(PyValueError::new_err("JSON int values must fit in 64 bits"));
+    }
+    if let Ok(number) = value.cast::<PyFloat>() {
+        return Number::from_f64(number.value())
+            .map(Value::Number)
+            .ok_or_else(|| PyValueError::new_err("JSON float values must be finite"));
+    }
+    if let Ok(text) = value.cast::<PyString>() {
+        return Ok(Value::String(text.to_str()?.to_string()));
+    }
+    if value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>() {
+        enter_container(value, containers, limit)?;
+        let items = value
+            .try_iter()?
+            .map(|item| convert_json_value(&item?, containers, limit))
+            .collect::<PyResult<Vec<_>>>()?;
+        containers.pop();
+        return Ok(Value::Array(items));
+    }
+    if let Ok(dict) = value.cast::<PyDict>() {
+        enter_container(value, containers, limit)?;
+        let mut map = Map::new();
+        for (k, v) in dict.iter() {
+            let key = k
+                .extract::<String>()
+                .map_err(|_| PyTypeError::new_err("JSON object keys must be str"))?;
+            map.insert(key, convert_json_value(&v, containers, limit)?);
+        }
+        containers.pop();
+        return Ok(Value::Object(map));
+    }
+    Err(PyTypeError::new_err(format!(
+        "values must be JSON-compatible (got {})",
+        value.get_type().name()?
+    )))
+}
+
+fn enter_container(
+    value: &Bound<'_, PyAny>,
+    containers: &mut Vec<usize>,
+    limit: usize,
+) -> PyResult<()> {
+    let id = value.as_ptr() as usize;
+    if containers.contains(&id) {
+        return Err(PyValueError::new_err("Circular reference detected"));
+    }
+    if containers.len() == limit {
+        return Err(PyValueError::new_err(format!(
+            "JSON values must not be nested more than {limit} levels deep"
+        )));
+    }
+    containers.push(id);
+    Ok(())
+}
+
+pub fn from_json_value<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(flag) => PyBool::new(py, *flag).to_owned().into_any(),
+        Value::Number(number) => {
+            if let Some(int) = number.as_i64() {
+                int.into_pyobject(py)?.into_any()
+            } else if let Some(int) = number.as_u64() {
+                int.into_pyobject(py)?.into_any()
+            } else {
+                number
+                    .as_f64()
+                    .unwrap_or(f64::NAN)
+                    .into_pyobject(py)?
+                    .into_any()
+            }
+        }
+        Value::String(text) => PyString::new(py, text).into_any(),
+        Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(from_json_value(py, item)?)?;
+            }
+            list.into_any()
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (k, v) in map {
+                dict.set_item(k, from_json_value(py, v)?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
@@ -0,0 +1,152 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyAny, PyDict};
+use serde_json::{Number, Value};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use super::json::{from_json_value, to_json_value};
+
+/// Error metadata: string keys with JSON-compatible values.
+pub type Metadata = HashMap<String, Value>;
+
+/// Read a `metadata=` argument (a dict with str keys) into native values.
+pub fn extract_metadata(py: Python<'_>, metadata: Option<Py<PyAny>>) -> PyResult<Metadata> {
+    let Some(meta_value) = metadata else {
+        return Ok(Metadata::new());
+    };
+    metadata_from_dict(meta_value.bind(py).cast_exact::<PyDict>()?, false)
+}
+
+/// Convert a metadata dict; `strict` only accepts `str` values (the interop format).
+pub fn metadata_from_dict(dict: &Bound<'_, PyDict>, strict: bool) -> PyResult<Metadata> {
+    let mut data = Metadata::new();
+    for (k, v) in dict.iter() {
+        let key = k.extract::<String>()?;
+        let value = if strict {
+            Value::String(v.extract::<String>().map_err(|_| {
+                PyTypeError::new_err(format!("metadata value for '{key}' must be str"))
+            })?)
+        } else {
+            // Inside a JSON document the value sits in the error object's `metadata` map.
+            to_json_value(&v, 2)?
+        };
+        data.insert(key, value);
+    }
+    Ok(data)
+}
+
+/// Build a metadata dict; `strict` encodes non-string values as JSON text.
+pub fn metadata_to_dict<'py>(
+    py: Python<'py>,
+    metadata: &Metadata,
+    strict: bool,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (k, v) in metadata {
+        match v {
+            Value::String(text) => dict.set_item(k, text)?,
+            _ if strict => dict.set_item(k, v.to_string())?,
+            _ => dict.set_item(k, from_json_value(py, v)?)?,
+        }
+    }
+    Ok(dict)
+}
+
+/// `==` for the entries of `a` and `b` outside `skip`. Integers and floats compare by
+/// value, as in Python (`1 == 1.0`).
+pub fn metadata_eq(a: &Metadata, b: &Metadata, skip: &[&str]) -> bool {
+    let kept = |(key, _): &(&String, &Value)| !skip.contains(&key.as_str());
+    a.iter().filter(kept).count() == b.iter().filter(kept).count()
+        && a.iter()
+            .filter(kept)
+            .all(|(key, value)| b.get(key).is_some_and(|other| value_eq(value, other)))
+}
+
+/// Hash the entries outside `skip` in key order, consistently with `metadata_eq`.
+pub fn hash_metadata<H: Hasher>(metadata: &Metadata, skip: &[&str], state: &mut H) {
+    let mut entries: Vec<_> = metadata
+        .iter()
+        .filter(|(key, _)| !skip.contains(&key.as_str()))
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries.len().hash(state);
+    for (key, value) in entries {
+        key.hash(state);
+        hash_value(value, state);
+    }
+}
+
+fn value_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => NumberKey::of(a) == NumberKey::of(b),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| value_eq(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, value)| b.get(key).is_some_and(|other| value_eq(value, other)))
+        }
+        _ => a == b,
+    }
+}
+
+fn hash_value<H: Hasher>(value: &Value, state: &mut H) {
+    std::mem::discriminant(value).hash(state);
+    match value {
+        Value::Null => {}
+        Value::Bool(flag) => flag.hash(state),
+        Value::Number(number) => NumberKey::of(number).hash(state),
+        Value::String(text) => text.hash(state),
+        Value::Array(items) => {
+            items.len().hash(state);
+            for item in items {
+                hash_value(item, state);
+            }
+        }
+        // `Map` iterates in key order.
+        Value::Object(map) => {
+            map.len().hash(state);
+            for (key, item) in map {
+                key.hash(state);
+                hash_value(item, state);
+            }
+        }
+    }
+}
+
+/// A number with integral floats folded into integers, so `1` and `1.0` are one key.
+#[derive(PartialEq)]
+enum NumberKey {
+    Int(i128),
+    Float(f64),
+}
+
+impl NumberKey {
+    fn of(number: &Number) -> Self {
+        if let Some(int) = number.as_i64() {
+            return NumberKey::Int(int.into());
+        }
+        if let Some(int) = number.as_u64() {
+            return NumberKey::Int(int.into());
+        }
+        // Metadata floats are finite, so this only misses non-integral values and
+        // magnitudes beyond any 64-bit integer.
+        let float = number.as_f64().unwrap_or(f64::NAN);
+        if float.fract() == 0.0 && float.abs() < 2f64.powi(64) {
+            NumberKey::Int(float as i128)
+        } else {
+            NumberKey::Float(float)
+        }
+    }
+}
+
+impl Hash for NumberKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            NumberKey::Int(int) => int.hash(state),
+            NumberKey::Float(float) => float.to_bits().hash(state),
+        }
+    }
+}
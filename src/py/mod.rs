@@ -1,7 +1,16 @@
+mod awaitable;
+mod do_notation;
 mod error;
+mod json;
+mod metadata;
 mod option;
 mod result;
+mod traceback;
 
-pub use error::{exception_to_error, Error, ErrorKindObj};
-pub use option::{py_none, py_some, OptionObj};
-pub use result::{py_bail_from_parts, py_ensure, py_err, py_err_from_parts, py_ok, ResultObj};
+pub use do_notation::{py_do, DoFn};
+pub use error::{exception_to_error, Error, ErrorKindObj, PyropustError};
+pub use option::{NoneObj, OptionObj, SomeObj};
+pub use result::{py_bail_from_parts, py_ensure, py_err_from_parts, ErrObj, OkObj, ResultObj};
+pub use traceback::{
+    set_traceback_capture, set_traceback_limit, traceback_capture_enabled, traceback_limit, Frame,
+};
@@ -1,12 +1,18 @@
-use pyo3::exceptions::{PyRuntimeError, PyTypeError};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple};
-use std::collections::HashMap;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyAny, PyDict, PyList, PyString, PyTuple, PyType};
+use pyo3::PyClassInitializer;
+use pyo3::{PyTraverseError, PyVisit};
+use serde_json::Value;
 
 use super::error::{build_error_from_parts, build_error_from_pyerr, Error};
-use super::result::{err, ok, ResultObj};
+use super::json::{
+    decode_payload, encode_payload, envelope_document, envelope_variant, parse_json_document,
+};
+use super::result::{collect_into, err, ok, ResultObj};
 
-#[pyclass(name = "Option")]
+#[pyclass(frozen, subclass, name = "Option", module = "pyropust")]
 pub struct OptionObj {
     pub is_some: bool,
     pub value: Option<Py<PyAny>>,
@@ -30,21 +36,20 @@ impl OptionObj {
         }
     }
 
-    fn map(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_some {
-            let value = self.value.as_ref().expect("some value");
-            let mapped = f.call1((value.clone_ref(py),))?;
-            Ok(some(mapped.into()))
+    fn map(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let this = slf.get();
+        if this.is_some {
+            let mapped = f.call1((this.value.as_ref().expect("some value"),))?;
+            some(slf.py(), mapped.into())
         } else {
-            Ok(none_())
+            Ok(slf.clone().unbind())
         }
     }
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (f, *, code, message, kind = None, metadata = None, op = None, path = None, expected = None, got = None))]
     fn map_try(
-        &self,
-        py: Python<'_>,
+        slf: &Bound<'_, Self>,
         f: Bound<'_, PyAny>,
         code: Py<PyAny>,
         message: String,
@@ -54,61 +59,39 @@ impl OptionObj {
         path: Option<Py<PyAny>>,
         expected: Option<String>,
         got: Option<String>,
-    ) -> PyResult<ResultObj> {
-        if !self.is_some {
-            let option_obj = none_();
-            let py_option = Py::new(py, option_obj)?;
-            return Ok(ok(py_option.into()));
+    ) -> PyResult<Py<ResultObj>> {
+        let py = slf.py();
+        let this = slf.get();
+        if !this.is_some {
+            return ok(py, slf.clone().into_any().unbind());
         }
 
-        let value = self.value.as_ref().expect("some value");
-        match f.call1((value.clone_ref(py),)) {
+        match f.call1((this.value.as_ref().expect("some value"),)) {
             Ok(mapped) => {
-                let option_obj = some(mapped.into());
-                let py_option = Py::new(py, option_obj)?;
-                Ok(ok(py_option.into()))
+                let option_obj = some(py, mapped.into());
+                let py_option = option_obj?;
+                ok(py, py_option.into())
             }
             Err(py_err) => {
-                let cause_obj = build_error_from_pyerr(py, py_err, "py_exception");
+                let cause_obj = build_error_from_pyerr(py, py_err, "py_exception", false);
                 let cause_ref = cause_obj.bind(py).extract::<PyRef<'_, Error>>()?;
 
-                let mut merged_metadata = extract_metadata(py, metadata)?;
-                if !merged_metadata.contains_key("cause_exception") {
-                    if let Some(value) = cause_ref.metadata.get("exception") {
-                        merged_metadata.insert("cause_exception".to_string(), value.to_string());
-                    }
-                }
-                if !merged_metadata.contains_key("cause_py_traceback") {
-                    if let Some(value) = cause_ref.metadata.get("py_traceback") {
-                        merged_metadata.insert("cause_py_traceback".to_string(), value.to_string());
-                    }
-                }
-
-                let metadata_value = if merged_metadata.is_empty() {
-                    None
-                } else {
-                    let dict = PyDict::new(py);
-                    for (k, v) in merged_metadata {
-                        dict.set_item(k, v)?;
-                    }
-                    Some(dict.into())
-                };
-
                 let kind = kind.or_else(|| Some(PyString::new(py, "Internal").into()));
 
-                let new_err = build_error_from_parts(
+                let mut new_err = build_error_from_parts(
                     py,
                     code,
                     &message,
                     kind,
-                    metadata_value,
+                    metadata,
                     op,
                     path,
                     expected,
                     got,
-                    Some(error_repr(&cause_ref)),
+                    Some(cause_obj.clone_ref(py).into_any()),
                 )?;
-                Ok(err(Py::new(py, new_err)?.into()))
+                new_err.inherit_cause_exception(&cause_ref);
+                err(py, Py::new(py, new_err)?.into())
             }
         }
     }
@@ -117,25 +100,25 @@ impl OptionObj {
         if self.is_some {
             Ok(self.value.as_ref().expect("some value").clone_ref(py))
         } else {
-            Ok(default.clone_ref(py))
+            Ok(default)
         }
     }
 
     // Query methods
-    fn is_some_and(&self, py: Python<'_>, predicate: Bound<'_, PyAny>) -> PyResult<bool> {
+    fn is_some_and(&self, predicate: Bound<'_, PyAny>) -> PyResult<bool> {
         if self.is_some {
             let value = self.value.as_ref().expect("some value");
-            let result = predicate.call1((value.clone_ref(py),))?;
+            let result = predicate.call1((value,))?;
             result.is_truthy()
         } else {
             Ok(false)
         }
     }
 
-    fn is_none_or(&self, py: Python<'_>, predicate: Bound<'_, PyAny>) -> PyResult<bool> {
+    fn is_none_or(&self, predicate: Bound<'_, PyAny>) -> PyResult<bool> {
         if self.is_some {
             let value = self.value.as_ref().expect("some value");
-            let result = predicate.call1((value.clone_ref(py),))?;
+            let result = predicate.call1((value,))?;
             result.is_truthy()
         } else {
             Ok(true)
@@ -161,30 +144,20 @@ impl OptionObj {
     }
 
     // Transformation methods
-    fn map_or(
-        &self,
-        py: Python<'_>,
-        default: Py<PyAny>,
-        f: Bound<'_, PyAny>,
-    ) -> PyResult<Py<PyAny>> {
+    fn map_or(&self, default: Py<PyAny>, f: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
         if self.is_some {
             let value = self.value.as_ref().expect("some value");
-            let result = f.call1((value.clone_ref(py),))?;
+            let result = f.call1((value,))?;
             Ok(result.into())
         } else {
             Ok(default)
         }
     }
 
-    fn map_or_else(
-        &self,
-        py: Python<'_>,
-        default_f: Bound<'_, PyAny>,
-        f: Bound<'_, PyAny>,
-    ) -> PyResult<Py<PyAny>> {
+    fn map_or_else(&self, default_f: Bound<'_, PyAny>, f: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
         if self.is_some {
             let value = self.value.as_ref().expect("some value");
-            let result = f.call1((value.clone_ref(py),))?;
+            let result = f.call1((value,))?;
             Ok(result.into())
         } else {
             let result = default_f.call0()?;
@@ -192,63 +165,42 @@ impl OptionObj {
         }
     }
 
-    fn inspect(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_some {
-            let value = self.value.as_ref().expect("some value");
-            f.call1((value.clone_ref(py),))?;
+    fn inspect(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        if let Some(value) = &slf.get().value {
+            f.call1((value,))?;
         }
-        Ok(OptionObj {
-            is_some: self.is_some,
-            value: self.value.as_ref().map(|v| v.clone_ref(py)),
-        })
+        Ok(slf.clone().unbind())
     }
 
-    fn filter(&self, py: Python<'_>, predicate: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_some {
-            let value = self.value.as_ref().expect("some value");
-            let result = predicate.call1((value.clone_ref(py),))?;
-            if result.is_truthy()? {
-                Ok(some(value.clone_ref(py)))
-            } else {
-                Ok(none_())
+    fn filter(slf: &Bound<'_, Self>, predicate: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        if let Some(value) = &slf.get().value {
+            if predicate.call1((value,))?.is_truthy()? {
+                return Ok(slf.clone().unbind());
             }
-        } else {
-            Ok(none_())
         }
+        none_(slf.py())
     }
 
     // Composition methods
-    fn and_(&self, py: Python<'_>, other: &Self) -> Self {
-        if self.is_some {
-            OptionObj {
-                is_some: other.is_some,
-                value: other.value.as_ref().map(|v| v.clone_ref(py)),
-            }
+    fn and_(slf: &Bound<'_, Self>, other: &Bound<'_, OptionObj>) -> Py<Self> {
+        if slf.get().is_some {
+            other.clone().unbind()
         } else {
-            none_()
+            slf.clone().unbind()
         }
     }
 
-    fn and_then(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_some {
-            let value = self.value.as_ref().expect("some value");
-            let out = f.call1((value.clone_ref(py),))?;
-            let option_type = py.get_type::<OptionObj>();
-            if !out.is_instance(option_type.as_any())? {
-                return Err(PyTypeError::new_err("and_then callback must return Option"));
-            }
-            let out_ref: PyRef<'_, OptionObj> = out.extract()?;
-            Ok(clone_option(py, &out_ref))
-        } else {
-            Ok(none_())
+    fn and_then(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        match &slf.get().value {
+            Some(value) => returned_option(f.call1((value,))?, "and_then"),
+            None => Ok(slf.clone().unbind()),
         }
     }
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (f, *, code, message, kind = None, metadata = None, op = None, path = None, expected = None, got = None))]
     fn and_then_try(
-        &self,
-        py: Python<'_>,
+        slf: &Bound<'_, Self>,
         f: Bound<'_, PyAny>,
         code: Py<PyAny>,
         message: String,
@@ -258,138 +210,85 @@ impl OptionObj {
         path: Option<Py<PyAny>>,
         expected: Option<String>,
         got: Option<String>,
-    ) -> PyResult<ResultObj> {
-        if !self.is_some {
-            let option_obj = none_();
-            let py_option = Py::new(py, option_obj)?;
-            return Ok(ok(py_option.into()));
+    ) -> PyResult<Py<ResultObj>> {
+        let py = slf.py();
+        let this = slf.get();
+        if !this.is_some {
+            return ok(py, slf.clone().into_any().unbind());
         }
 
-        let value = self.value.as_ref().expect("some value");
-        let out = f.call1((value.clone_ref(py),));
+        let out = f.call1((this.value.as_ref().expect("some value"),));
         let out = match out {
             Ok(out) => out,
             Err(py_err) => {
-                let cause_obj = build_error_from_pyerr(py, py_err, "py_exception");
+                let cause_obj = build_error_from_pyerr(py, py_err, "py_exception", false);
                 let cause_ref = cause_obj.bind(py).extract::<PyRef<'_, Error>>()?;
 
-                let mut merged_metadata = extract_metadata(py, metadata)?;
-                if !merged_metadata.contains_key("cause_exception") {
-                    if let Some(value) = cause_ref.metadata.get("exception") {
-                        merged_metadata.insert("cause_exception".to_string(), value.to_string());
-                    }
-                }
-                if !merged_metadata.contains_key("cause_py_traceback") {
-                    if let Some(value) = cause_ref.metadata.get("py_traceback") {
-                        merged_metadata.insert("cause_py_traceback".to_string(), value.to_string());
-                    }
-                }
-
-                let metadata_value = if merged_metadata.is_empty() {
-                    None
-                } else {
-                    let dict = PyDict::new(py);
-                    for (k, v) in merged_metadata {
-                        dict.set_item(k, v)?;
-                    }
-                    Some(dict.into())
-                };
-
                 let kind = kind.or_else(|| Some(PyString::new(py, "Internal").into()));
 
-                let new_err = build_error_from_parts(
+                let mut new_err = build_error_from_parts(
                     py,
                     code,
                     &message,
                     kind,
-                    metadata_value,
+                    metadata,
                     op,
                     path,
                     expected,
                     got,
-                    Some(error_repr(&cause_ref)),
+                    Some(cause_obj.clone_ref(py).into_any()),
                 )?;
-                return Ok(err(Py::new(py, new_err)?.into()));
+                new_err.inherit_cause_exception(&cause_ref);
+                return err(py, Py::new(py, new_err)?.into());
             }
         };
 
-        let option_type = py.get_type::<OptionObj>();
-        if !out.is_instance(option_type.as_any())? {
-            return Err(PyTypeError::new_err(
-                "and_then_try callback must return Option",
-            ));
-        }
-        let out_ref: PyRef<'_, OptionObj> = out.extract()?;
-        let option_obj = clone_option(py, &out_ref);
-        let py_option = Py::new(py, option_obj)?;
-        Ok(ok(py_option.into()))
+        let option = returned_option(out, "and_then_try")?;
+        ok(py, option.into_any())
     }
 
-    fn or_(&self, py: Python<'_>, other: &Self) -> Self {
-        if self.is_some {
-            OptionObj {
-                is_some: self.is_some,
-                value: self.value.as_ref().map(|v| v.clone_ref(py)),
-            }
+    fn or_(slf: &Bound<'_, Self>, other: &Bound<'_, OptionObj>) -> Py<Self> {
+        if slf.get().is_some {
+            slf.clone().unbind()
         } else {
-            OptionObj {
-                is_some: other.is_some,
-                value: other.value.as_ref().map(|v| v.clone_ref(py)),
-            }
+            other.clone().unbind()
         }
     }
 
-    fn or_else(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_some {
-            Ok(OptionObj {
-                is_some: self.is_some,
-                value: self.value.as_ref().map(|v| v.clone_ref(py)),
-            })
+    fn or_else(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        if slf.get().is_some {
+            Ok(slf.clone().unbind())
         } else {
-            let out = f.call0()?;
-            let option_type = py.get_type::<OptionObj>();
-            if !out.is_instance(option_type.as_any())? {
-                return Err(PyTypeError::new_err("or_else callback must return Option"));
-            }
-            let out_ref: PyRef<'_, OptionObj> = out.extract()?;
-            Ok(clone_option(py, &out_ref))
+            returned_option(f.call0()?, "or_else")
         }
     }
 
-    fn xor(&self, py: Python<'_>, other: &Self) -> Self {
-        match (self.is_some, other.is_some) {
-            (true, false) => OptionObj {
-                is_some: true,
-                value: self.value.as_ref().map(|v| v.clone_ref(py)),
-            },
-            (false, true) => OptionObj {
-                is_some: true,
-                value: other.value.as_ref().map(|v| v.clone_ref(py)),
-            },
-            _ => none_(),
+    fn xor(slf: &Bound<'_, Self>, other: &Bound<'_, OptionObj>) -> PyResult<Py<Self>> {
+        match (slf.get().is_some, other.get().is_some) {
+            (true, false) => Ok(slf.clone().unbind()),
+            (false, true) => Ok(other.clone().unbind()),
+            _ => none_(slf.py()),
         }
     }
 
     // Utility methods
-    fn flatten(&self, py: Python<'_>) -> PyResult<Self> {
-        if self.is_some {
-            let value = self.value.as_ref().expect("some value");
-            let option_type = py.get_type::<OptionObj>();
-            if !value.bind(py).is_instance(option_type.as_any())? {
-                return Err(PyTypeError::new_err(
-                    "flatten requires Some value to be an Option",
-                ));
+    fn flatten(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        match &slf.get().value {
+            Some(value) => {
+                let inner = value.bind(slf.py()).cast::<OptionObj>().map_err(|_| {
+                    PyTypeError::new_err("flatten requires Some value to be an Option")
+                })?;
+                Ok(inner.clone().unbind())
             }
-            let inner_ref: PyRef<'_, OptionObj> = value.extract(py)?;
-            Ok(clone_option(py, &inner_ref))
-        } else {
-            Ok(none_())
+            None => Ok(slf.clone().unbind()),
         }
     }
 
-    fn transpose(&self, py: Python<'_>) -> PyResult<ResultObj> {
-        if self.is_some {
-            let value = self.value.as_ref().expect("some value");
+    fn transpose(slf: &Bound<'_, Self>) -> PyResult<Py<ResultObj>> {
+        let py = slf.py();
+        let this = slf.get();
+        if this.is_some {
+            let value = this.value.as_ref().expect("some value");
             let result_type = py.get_type::<ResultObj>();
             if !value.bind(py).is_instance(result_type.as_any())? {
                 return Err(PyTypeError::new_err(
@@ -399,39 +298,37 @@ impl OptionObj {
             let res_ref: PyRef<'_, ResultObj> = value.extract(py)?;
             if res_ref.is_ok {
                 let inner_value = res_ref.ok.as_ref().expect("ok value").clone_ref(py);
-                let option_obj = some(inner_value);
-                let py_option = Py::new(py, option_obj)?;
-                Ok(ok(py_option.into()))
+                let option_obj = some(py, inner_value);
+                let py_option = option_obj?;
+                ok(py, py_option.into())
             } else {
                 let err_value = res_ref.err.as_ref().expect("err value").clone_ref(py);
-                Ok(err(err_value))
+                err(py, err_value)
             }
         } else {
-            let option_obj = none_();
-            let py_option = Py::new(py, option_obj)?;
-            Ok(ok(py_option.into()))
+            ok(py, slf.clone().into_any().unbind())
         }
     }
 
-    fn zip(&self, py: Python<'_>, other: &Self) -> PyResult<Self> {
+    fn zip(&self, py: Python<'_>, other: &Self) -> PyResult<Py<Self>> {
         if self.is_some && other.is_some {
             let value1 = self.value.as_ref().expect("some value");
             let value2 = other.value.as_ref().expect("some value");
-            let tuple = PyTuple::new(py, &[value1.clone_ref(py), value2.clone_ref(py)])?;
-            Ok(some(tuple.into()))
+            let tuple = PyTuple::new(py, [value1, value2])?;
+            some(py, tuple.into())
         } else {
-            Ok(none_())
+            none_(py)
         }
     }
 
-    fn zip_with(&self, py: Python<'_>, other: &Self, f: Bound<'_, PyAny>) -> PyResult<Self> {
+    fn zip_with(&self, py: Python<'_>, other: &Self, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
         if self.is_some && other.is_some {
             let value1 = self.value.as_ref().expect("some value");
             let value2 = other.value.as_ref().expect("some value");
-            let result = f.call1((value1.clone_ref(py), value2.clone_ref(py)))?;
-            Ok(some(result.into()))
+            let result = f.call1((value1, value2))?;
+            some(py, result.into())
         } else {
-            Ok(none_())
+            none_(py)
         }
     }
 
@@ -449,16 +346,16 @@ impl OptionObj {
         path: Option<Py<PyAny>>,
         expected: Option<String>,
         got: Option<String>,
-        cause: Option<String>,
-    ) -> PyResult<ResultObj> {
+        cause: Option<Py<PyAny>>,
+    ) -> PyResult<Py<ResultObj>> {
         if self.is_some {
             let value = self.value.as_ref().expect("some value").clone_ref(py);
-            Ok(ok(value))
+            ok(py, value)
         } else {
             let error = build_error_from_parts(
                 py, code, message, kind, metadata, op, path, expected, got, cause,
             )?;
-            Ok(err(Py::new(py, error)?.into()))
+            err(py, Py::new(py, error)?.into())
         }
     }
 
@@ -475,82 +372,282 @@ impl OptionObj {
         path: Option<Py<PyAny>>,
         expected: Option<String>,
         got: Option<String>,
-        cause: Option<String>,
-    ) -> PyResult<ResultObj> {
+        cause: Option<Py<PyAny>>,
+    ) -> PyResult<Py<ResultObj>> {
         if self.is_some {
             let value = self.value.as_ref().expect("some value").clone_ref(py);
-            Ok(ok(value))
+            ok(py, value)
         } else {
             let error_ref = f.call0()?;
             let error_type = py.get_type::<Error>();
             if error_ref.is_instance(error_type.as_any())? {
-                Ok(err(error_ref.unbind()))
+                err(py, error_ref.unbind())
             } else {
                 let message = error_ref.extract::<String>()?;
                 let error = build_error_from_parts(
                     py, code, &message, kind, metadata, op, path, expected, got, cause,
                 )?;
-                Ok(err(Py::new(py, error)?.into()))
+                err(py, Py::new(py, error)?.into())
             }
         }
     }
+
+    /// `{"some": value}` or `{"none": None}`; `encoder` maps the Some value.
+    #[pyo3(signature = (*, encoder = None))]
+    fn to_dict(&self, py: Python<'_>, encoder: Option<Bound<'_, PyAny>>) -> PyResult<Py<PyAny>> {
+        let dict = PyDict::new(py);
+        match (&self.value, &encoder) {
+            (Some(value), Some(encoder)) => dict.set_item("some", encoder.call1((value,))?)?,
+            (Some(value), None) => dict.set_item("some", value)?,
+            (None, _) => dict.set_item("none", py.None())?,
+        }
+        Ok(dict.into())
+    }
+
+    /// Collect the Some values of `options` into `into` (a list by default),
+    /// stopping at the first `None_`.
+    #[classmethod]
+    #[pyo3(signature = (options, *, into = None))]
+    fn collect(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        options: &Bound<'_, PyAny>,
+        into: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Self>> {
+        let values = PyList::empty(py);
+        for item in options.try_iter()? {
+            let item = item?;
+            let option = item
+                .cast::<OptionObj>()
+                .map_err(|_| PyTypeError::new_err("collect expects Option values"))?
+                .borrow();
+            if !option.is_some {
+                return none_(py);
+            }
+            values.append(option.value.as_ref().expect("some value"))?;
+        }
+        some(py, collect_into(values, into.as_ref())?)
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (data, *, decoder = None))]
+    fn from_dict(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        data: &Bound<'_, PyAny>,
+        decoder: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Self>> {
+        let dict = data.cast_exact::<PyDict>()?;
+        if dict.len() == 1 {
+            if let Some(value) = dict.get_item("some")? {
+                let value = match &decoder {
+                    Some(decoder) => decoder.call1((value,))?,
+                    None => value,
+                };
+                return some(py, value.unbind());
+            }
+            if let Some(value) = dict.get_item("none")? {
+                if !value.is_none() {
+                    return Err(PyValueError::new_err("'none' must be None"));
+                }
+                return none_(py);
+            }
+        }
+        Err(PyValueError::new_err(
+            "Option dict must have exactly one of 'some' or 'none'",
+        ))
+    }
+
+    /// Versioned JSON envelope: `{"schema_version": 1, "some": ...}` or `{..., "none": null}`.
+    #[pyo3(signature = (*, encoder = None))]
+    fn to_json(&self, py: Python<'_>, encoder: Option<Bound<'_, PyAny>>) -> PyResult<String> {
+        let document = match &self.value {
+            Some(value) => {
+                envelope_document("some", encode_payload(value.bind(py), encoder.as_ref())?)?
+            }
+            None => envelope_document("none", Value::Null)?,
+        };
+        Ok(document.to_string())
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (data, *, decoder = None))]
+    fn from_json(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        data: &Bound<'_, PyAny>,
+        decoder: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Self>> {
+        let invalid = |msg: String| PyValueError::new_err(format!("invalid Option JSON: {msg}"));
+        let document = parse_json_document(data, "Option")?;
+        match envelope_variant(&document, ["some", "none"]).map_err(invalid)? {
+            ("some", payload) => some(py, decode_payload(py, payload, decoder.as_ref())?.unbind()),
+            (_, Value::Null) => none_(py),
+            _ => Err(invalid("'none' must be null".to_string())),
+        }
+    }
+
+    fn __eq__(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        let Ok(other) = other.cast::<OptionObj>() else {
+            return Ok(py.NotImplemented());
+        };
+        let other = other.borrow();
+        let equal = match (&self.value, &other.value) {
+            (Some(a), Some(b)) => a.bind(py).eq(b.bind(py))?,
+            (None, None) => true,
+            _ => false,
+        };
+        Ok(equal.into_pyobject(py)?.to_owned().into_any().unbind())
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        match &self.value {
+            Some(value) => (true, value).into_pyobject(py)?.hash(),
+            None => (false,).into_pyobject(py)?.hash(),
+        }
+    }
+
+    // Frozen like `Result`: reporting the payload lets the GC clear the cycle elsewhere.
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.value)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        match &self.value {
+            Some(value) => Ok(format!("Some({})", value.bind(py).repr()?)),
+            None => Ok("None_".to_string()),
+        }
+    }
+
+    // `if option:` is almost always a forgotten `is_some()`, so refuse to guess.
+    fn __bool__(&self) -> PyResult<bool> {
+        Err(PyTypeError::new_err(
+            "Option has no truth value; use is_some() or is_none()",
+        ))
+    }
+
+    // Pickle through the public constructors: `Some(value)` / `None_()`.
+    fn __reduce__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        match &self.value {
+            Some(value) => Ok((
+                py.get_type::<SomeObj>(),
+                PyTuple::new(py, [value.clone_ref(py)])?,
+            )),
+            None => Ok((py.get_type::<NoneObj>(), PyTuple::empty(py))),
+        }
+    }
+
+    // Options are immutable, so a copy is the option itself.
+    fn __copy__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__(&self, py: Python<'_>, memo: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        match &self.value {
+            Some(value) => {
+                let copied = py
+                    .import("copy")?
+                    .call_method1("deepcopy", (value.clone_ref(py), memo))?;
+                some(py, copied.unbind())
+            }
+            None => none_(py),
+        }
+    }
 }
 
-// Python-facing constructor functions
-#[pyfunction(name = "Some")]
-pub fn py_some(value: Py<PyAny>) -> OptionObj {
-    some(value)
+// Python-facing variant classes. `Some(v)` / `None_()` construct them directly
+// and `match` class patterns bind the payload through `__match_args__`.
+#[pyclass(frozen, extends = OptionObj, name = "Some", module = "pyropust")]
+pub struct SomeObj;
+
+#[pymethods]
+impl SomeObj {
+    #[classattr]
+    #[pyo3(name = "__match_args__")]
+    const MATCH_ARGS: (&'static str,) = ("value",);
+
+    #[new]
+    fn new(value: Py<PyAny>) -> (Self, OptionObj) {
+        (SomeObj, some_value(value))
+    }
+
+    #[getter]
+    fn value(slf: PyRef<'_, Self>, py: Python<'_>) -> Py<PyAny> {
+        slf.as_super()
+            .value
+            .as_ref()
+            .expect("some value")
+            .clone_ref(py)
+    }
 }
 
-#[pyfunction(name = "None_")]
-pub fn py_none() -> OptionObj {
-    none_()
+#[pyclass(frozen, extends = OptionObj, name = "None_", module = "pyropust")]
+pub struct NoneObj;
+
+#[pymethods]
+impl NoneObj {
+    #[classattr]
+    #[pyo3(name = "__match_args__")]
+    const MATCH_ARGS: () = ();
+
+    #[new]
+    fn new(py: Python<'_>) -> PyResult<Py<Self>> {
+        Ok(none_singleton(py)?.clone_ref(py))
+    }
 }
 
 // Internal constructor functions
-pub fn some(value: Py<PyAny>) -> OptionObj {
+pub fn some(py: Python<'_>, value: Py<PyAny>) -> PyResult<Py<OptionObj>> {
+    some_value(value).into_instance(py)
+}
+
+pub fn none_(py: Python<'_>) -> PyResult<Py<OptionObj>> {
+    Ok(none_singleton(py)?.bind(py).as_super().clone().unbind())
+}
+
+/// `None_` carries no state and is immutable, so every `None_()` is this instance.
+static NONE: PyOnceLock<Py<NoneObj>> = PyOnceLock::new();
+
+fn none_singleton(py: Python<'_>) -> PyResult<&Py<NoneObj>> {
+    NONE.get_or_try_init(py, || {
+        Py::new(
+            py,
+            PyClassInitializer::from(none_value()).add_subclass(NoneObj),
+        )
+    })
+}
+
+fn some_value(value: Py<PyAny>) -> OptionObj {
     OptionObj {
         is_some: true,
         value: Some(value),
     }
 }
 
-pub fn none_() -> OptionObj {
+fn none_value() -> OptionObj {
     OptionObj {
         is_some: false,
         value: None,
     }
 }
 
-fn clone_option(py: Python<'_>, out_ref: &PyRef<'_, OptionObj>) -> OptionObj {
-    OptionObj {
-        is_some: out_ref.is_some,
-        value: out_ref.value.as_ref().map(|v| v.clone_ref(py)),
+impl OptionObj {
+    /// Allocate as `Some`, or return the shared `None_`.
+    pub fn into_instance(self, py: Python<'_>) -> PyResult<Py<OptionObj>> {
+        if !self.is_some {
+            return none_(py);
+        }
+        let init = PyClassInitializer::from(self).add_subclass(SomeObj);
+        Ok(Bound::new(py, init)?.into_super().unbind())
     }
 }
 
-fn error_repr(err: &Error) -> String {
-    format!(
-        "Error(kind=ErrorKind.{}, code='{}', message='{}')",
-        err.kind.as_str(),
-        err.code,
-        err.message
-    )
-}
-
-fn extract_metadata(
-    py: Python<'_>,
-    metadata: Option<Py<PyAny>>,
-) -> PyResult<HashMap<String, String>> {
-    let mut data = HashMap::new();
-    let Some(meta_value) = metadata else {
-        return Ok(data);
-    };
-    let meta_dict = meta_value.bind(py).cast_exact::<PyDict>()?;
-    for (k, v) in meta_dict.iter() {
-        let key = k.extract::<String>()?;
-        let value = v.extract::<String>()?;
-        data.insert(key, value);
-    }
-    Ok(data)
+/// An `Option` returned by a callback, passed through as is.
+fn returned_option(out: Bound<'_, PyAny>, method: &str) -> PyResult<Py<OptionObj>> {
+    out.cast_into::<OptionObj>()
+        .map(Bound::unbind)
+        .map_err(|_| PyTypeError::new_err(format!("{method} callback must return Option")))
 }
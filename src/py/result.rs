@@ -1,13 +1,29 @@
-use pyo3::exceptions::{PyBaseException, PyRuntimeError, PyTypeError};
+use pyo3::exceptions::{
+    PyBaseException, PyBaseExceptionGroup, PyException, PyRuntimeError, PyTypeError, PyValueError,
+};
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyList, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDict, PyIterator, PyList, PySendResult, PyString, PyTuple, PyType};
 use pyo3::Bound;
-use std::collections::HashMap;
+use pyo3::PyClassInitializer;
+use pyo3::{PyTraverseError, PyVisit};
+use serde_json::Value;
+use std::sync::Arc;
 
-use super::error::{build_error_from_parts, build_error_from_pyerr, Error, PathItem};
+use super::awaitable::{
+    await_iter, is_cancelled, reused_awaitable, stop_iteration, throw_into, Resume,
+};
+use super::error::{
+    build_error_from_parts, build_error_from_pyerr, error_from_dict, pyropust_error, Error,
+    ErrorKind, PathItem,
+};
+use super::json::{
+    decode_payload, encode_payload, envelope_document, envelope_variant, error_from_json_object,
+    parse_json_document,
+};
+use super::metadata::{extract_metadata, Metadata};
 use super::option::{none_, some, OptionObj};
 
-#[pyclass(name = "Result")]
+#[pyclass(frozen, subclass, name = "Result", module = "pyropust")]
 pub struct ResultObj {
     pub is_ok: bool,
     pub ok: Option<Py<PyAny>>,
@@ -28,7 +44,9 @@ impl ResultObj {
         if self.is_ok {
             Ok(self.ok.as_ref().expect("ok value").clone_ref(py))
         } else {
-            Err(PyRuntimeError::new_err("called unwrap() on Err"))
+            let error = self.err.as_ref().expect("err value");
+            let message = format!("called unwrap() on Err: {}", error.bind(py).str()?);
+            Err(pyropust_error(py, message, error))
         }
     }
 
@@ -44,7 +62,8 @@ impl ResultObj {
         if self.is_ok {
             Ok(self.ok.as_ref().expect("ok value").clone_ref(py))
         } else {
-            Err(PyRuntimeError::new_err(msg.to_string()))
+            let error = self.err.as_ref().expect("err value");
+            Err(pyropust_error(py, msg.to_string(), error))
         }
     }
 
@@ -69,42 +88,43 @@ impl ResultObj {
             Ok(self.ok.as_ref().expect("ok value").clone_ref(py))
         } else {
             let err_value = self.err.as_ref().expect("err value");
-            let result = f.call1((err_value.clone_ref(py),))?;
+            let result = f.call1((err_value,))?;
             Ok(result.into())
         }
     }
 
-    fn ok(&self, py: Python<'_>) -> OptionObj {
+    fn ok(&self, py: Python<'_>) -> PyResult<Py<OptionObj>> {
         if self.is_ok {
-            some(self.ok.as_ref().expect("ok value").clone_ref(py))
+            some(py, self.ok.as_ref().expect("ok value").clone_ref(py))
         } else {
-            none_()
+            none_(py)
         }
     }
 
-    fn err(&self, py: Python<'_>) -> OptionObj {
+    fn err(&self, py: Python<'_>) -> PyResult<Py<OptionObj>> {
         if self.is_ok {
-            none_()
+            none_(py)
         } else {
-            some(self.err.as_ref().expect("err value").clone_ref(py))
+            some(py, self.err.as_ref().expect("err value").clone_ref(py))
         }
     }
 
-    fn map(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_ok {
-            let value = self.ok.as_ref().expect("ok value");
-            let mapped = f.call1((value.clone_ref(py),))?;
-            Ok(ok(mapped.into()))
+    fn map(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let py = slf.py();
+        let this = slf.get();
+        if this.is_ok {
+            let value = this.ok.as_ref().expect("ok value");
+            let mapped = f.call1((value,))?;
+            ok(py, mapped.into())
         } else {
-            Ok(err(self.err.as_ref().expect("err value").clone_ref(py)))
+            Ok(slf.clone().unbind())
         }
     }
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (f, *, code, message, kind = None, metadata = None, op = None, path = None, expected = None, got = None))]
     fn map_try(
-        &self,
-        py: Python<'_>,
+        slf: &Bound<'_, Self>,
         f: Bound<'_, PyAny>,
         code: Py<PyAny>,
         message: String,
@@ -114,196 +134,133 @@ impl ResultObj {
         path: Option<Py<PyAny>>,
         expected: Option<String>,
         got: Option<String>,
-    ) -> PyResult<Self> {
-        if !self.is_ok {
-            return Ok(err(self.err.as_ref().expect("err value").clone_ref(py)));
+    ) -> PyResult<Py<Self>> {
+        let py = slf.py();
+        let this = slf.get();
+        if !this.is_ok {
+            return Ok(slf.clone().unbind());
         }
 
-        let value = self.ok.as_ref().expect("ok value");
-        match f.call1((value.clone_ref(py),)) {
-            Ok(mapped) => Ok(ok(mapped.into())),
+        let value = this.ok.as_ref().expect("ok value");
+        match f.call1((value,)) {
+            Ok(mapped) => ok(py, mapped.into()),
             Err(py_err) => {
-                let cause_obj = build_error_from_pyerr(py, py_err, "py_exception");
+                let cause_obj = build_error_from_pyerr(py, py_err, "py_exception", false);
                 let cause_ref = cause_obj.bind(py).extract::<PyRef<'_, Error>>()?;
 
-                let mut merged_metadata = extract_metadata(py, metadata)?;
-                if !merged_metadata.contains_key("cause_exception") {
-                    if let Some(value) = cause_ref.metadata.get("exception") {
-                        merged_metadata.insert("cause_exception".to_string(), value.to_string());
-                    }
-                }
-                if !merged_metadata.contains_key("cause_py_traceback") {
-                    if let Some(value) = cause_ref.metadata.get("py_traceback") {
-                        merged_metadata.insert("cause_py_traceback".to_string(), value.to_string());
-                    }
-                }
-
-                let metadata_value = if merged_metadata.is_empty() {
-                    None
-                } else {
-                    let dict = PyDict::new(py);
-                    for (k, v) in merged_metadata {
-                        dict.set_item(k, v)?;
-                    }
-                    Some(dict.into())
-                };
-
                 let kind = kind.or_else(|| Some(PyString::new(py, "Internal").into()));
 
-                let new_err = build_error_from_parts(
+                let mut new_err = build_error_from_parts(
                     py,
                     code,
                     &message,
                     kind,
-                    metadata_value,
+                    metadata,
                     op,
                     path,
                     expected,
                     got,
-                    Some(error_repr(&cause_ref)),
+                    Some(cause_obj.clone_ref(py).into_any()),
                 )?;
-                Ok(err(Py::new(py, new_err)?.into()))
+                new_err.inherit_cause_exception(&cause_ref);
+                err(py, Py::new(py, new_err)?.into())
             }
         }
     }
 
-    fn map_err(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_ok {
-            Ok(ok(self.ok.as_ref().expect("ok value").clone_ref(py)))
+    fn map_err(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let py = slf.py();
+        let this = slf.get();
+        if this.is_ok {
+            Ok(slf.clone().unbind())
         } else {
-            let value = self.err.as_ref().expect("err value");
-            let mapped = f.call1((value.clone_ref(py),))?;
-            Ok(err(mapped.into()))
+            let value = this.err.as_ref().expect("err value");
+            let mapped = f.call1((value,))?;
+            err(py, mapped.into())
         }
     }
 
-    fn map_or(
-        &self,
-        py: Python<'_>,
-        default: Py<PyAny>,
-        f: Bound<'_, PyAny>,
-    ) -> PyResult<Py<PyAny>> {
+    fn map_or(&self, default: Py<PyAny>, f: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
         if self.is_ok {
             let value = self.ok.as_ref().expect("ok value");
-            let result = f.call1((value.clone_ref(py),))?;
+            let result = f.call1((value,))?;
             Ok(result.into())
         } else {
             Ok(default)
         }
     }
 
-    fn map_or_else(
-        &self,
-        py: Python<'_>,
-        default_f: Bound<'_, PyAny>,
-        f: Bound<'_, PyAny>,
-    ) -> PyResult<Py<PyAny>> {
+    fn map_or_else(&self, default_f: Bound<'_, PyAny>, f: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
         if self.is_ok {
             let value = self.ok.as_ref().expect("ok value");
-            let result = f.call1((value.clone_ref(py),))?;
+            let result = f.call1((value,))?;
             Ok(result.into())
         } else {
             let err_value = self.err.as_ref().expect("err value");
-            let result = default_f.call1((err_value.clone_ref(py),))?;
+            let result = default_f.call1((err_value,))?;
             Ok(result.into())
         }
     }
 
-    fn inspect(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_ok {
-            let value = self.ok.as_ref().expect("ok value");
-            f.call1((value.clone_ref(py),))?;
+    fn inspect(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let this = slf.get();
+        if this.is_ok {
+            let value = this.ok.as_ref().expect("ok value");
+            f.call1((value,))?;
         }
-        Ok(ResultObj {
-            is_ok: self.is_ok,
-            ok: self.ok.as_ref().map(|v| v.clone_ref(py)),
-            err: self.err.as_ref().map(|v| v.clone_ref(py)),
-        })
+        Ok(slf.clone().unbind())
     }
 
-    fn inspect_err(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if !self.is_ok {
-            let value = self.err.as_ref().expect("err value");
-            f.call1((value.clone_ref(py),))?;
+    fn inspect_err(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let this = slf.get();
+        if !this.is_ok {
+            let value = this.err.as_ref().expect("err value");
+            f.call1((value,))?;
         }
-        Ok(ResultObj {
-            is_ok: self.is_ok,
-            ok: self.ok.as_ref().map(|v| v.clone_ref(py)),
-            err: self.err.as_ref().map(|v| v.clone_ref(py)),
-        })
+        Ok(slf.clone().unbind())
     }
 
-    fn and_(&self, py: Python<'_>, other: &Self) -> Self {
-        if self.is_ok {
-            ResultObj {
-                is_ok: other.is_ok,
-                ok: other.ok.as_ref().map(|v| v.clone_ref(py)),
-                err: other.err.as_ref().map(|v| v.clone_ref(py)),
-            }
+    fn and_(slf: &Bound<'_, Self>, other: &Bound<'_, ResultObj>) -> Py<Self> {
+        if slf.get().is_ok {
+            other.clone().unbind()
         } else {
-            ResultObj {
-                is_ok: self.is_ok,
-                ok: self.ok.as_ref().map(|v| v.clone_ref(py)),
-                err: self.err.as_ref().map(|v| v.clone_ref(py)),
-            }
+            slf.clone().unbind()
         }
     }
 
-    fn or_(&self, py: Python<'_>, other: &Self) -> Self {
-        if self.is_ok {
-            ResultObj {
-                is_ok: self.is_ok,
-                ok: self.ok.as_ref().map(|v| v.clone_ref(py)),
-                err: self.err.as_ref().map(|v| v.clone_ref(py)),
-            }
+    fn or_(slf: &Bound<'_, Self>, other: &Bound<'_, ResultObj>) -> Py<Self> {
+        if slf.get().is_ok {
+            slf.clone().unbind()
         } else {
-            ResultObj {
-                is_ok: other.is_ok,
-                ok: other.ok.as_ref().map(|v| v.clone_ref(py)),
-                err: other.err.as_ref().map(|v| v.clone_ref(py)),
-            }
+            other.clone().unbind()
         }
     }
 
-    fn or_else(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_ok {
-            Ok(ResultObj {
-                is_ok: self.is_ok,
-                ok: self.ok.as_ref().map(|v| v.clone_ref(py)),
-                err: self.err.as_ref().map(|v| v.clone_ref(py)),
-            })
+    fn or_else(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let this = slf.get();
+        if this.is_ok {
+            Ok(slf.clone().unbind())
         } else {
-            let err_value = self.err.as_ref().expect("err value");
-            let out = f.call1((err_value.clone_ref(py),))?;
-            let result_type = py.get_type::<ResultObj>();
-            if !out.is_instance(result_type.as_any())? {
-                return Err(PyTypeError::new_err("or_else callback must return Result"));
-            }
-            let out_ref: PyRef<'_, ResultObj> = out.extract()?;
-            Ok(clone_result(py, &out_ref))
+            let out = f.call1((this.err.as_ref().expect("err value"),))?;
+            returned_result(out, "or_else")
         }
     }
 
-    fn and_then(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<Self> {
-        if self.is_ok {
-            let value = self.ok.as_ref().expect("ok value");
-            let out = f.call1((value.clone_ref(py),))?;
-            let result_type = py.get_type::<ResultObj>();
-            if !out.is_instance(result_type.as_any())? {
-                return Err(PyTypeError::new_err("and_then callback must return Result"));
-            }
-            let out_ref: PyRef<'_, ResultObj> = out.extract()?;
-            Ok(clone_result(py, &out_ref))
+    fn and_then(slf: &Bound<'_, Self>, f: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let this = slf.get();
+        if this.is_ok {
+            let value = this.ok.as_ref().expect("ok value");
+            let out = f.call1((value,))?;
+            returned_result(out, "and_then")
         } else {
-            Ok(err(self.err.as_ref().expect("err value").clone_ref(py)))
+            Ok(slf.clone().unbind())
         }
     }
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (f, *, code, message, kind = None, metadata = None, op = None, path = None, expected = None, got = None))]
     fn and_then_try(
-        &self,
-        py: Python<'_>,
+        slf: &Bound<'_, Self>,
         f: Bound<'_, PyAny>,
         code: Py<PyAny>,
         message: String,
@@ -313,108 +270,83 @@ impl ResultObj {
         path: Option<Py<PyAny>>,
         expected: Option<String>,
         got: Option<String>,
-    ) -> PyResult<Self> {
-        if !self.is_ok {
-            return Ok(err(self.err.as_ref().expect("err value").clone_ref(py)));
+    ) -> PyResult<Py<Self>> {
+        let py = slf.py();
+        let this = slf.get();
+        if !this.is_ok {
+            return Ok(slf.clone().unbind());
         }
 
-        let value = self.ok.as_ref().expect("ok value");
-        let out = f.call1((value.clone_ref(py),));
+        let value = this.ok.as_ref().expect("ok value");
+        let out = f.call1((value,));
         let out = match out {
             Ok(out) => out,
             Err(py_err) => {
-                let cause_obj = build_error_from_pyerr(py, py_err, "py_exception");
+                let cause_obj = build_error_from_pyerr(py, py_err, "py_exception", false);
                 let cause_ref = cause_obj.bind(py).extract::<PyRef<'_, Error>>()?;
 
-                let mut merged_metadata = extract_metadata(py, metadata)?;
-                if !merged_metadata.contains_key("cause_exception") {
-                    if let Some(value) = cause_ref.metadata.get("exception") {
-                        merged_metadata.insert("cause_exception".to_string(), value.to_string());
-                    }
-                }
-                if !merged_metadata.contains_key("cause_py_traceback") {
-                    if let Some(value) = cause_ref.metadata.get("py_traceback") {
-                        merged_metadata.insert("cause_py_traceback".to_string(), value.to_string());
-                    }
-                }
-
-                let metadata_value = if merged_metadata.is_empty() {
-                    None
-                } else {
-                    let dict = PyDict::new(py);
-                    for (k, v) in merged_metadata {
-                        dict.set_item(k, v)?;
-                    }
-                    Some(dict.into())
-                };
-
                 let kind = kind.or_else(|| Some(PyString::new(py, "Internal").into()));
 
-                let new_err = build_error_from_parts(
+                let mut new_err = build_error_from_parts(
                     py,
                     code,
                     &message,
                     kind,
-                    metadata_value,
+                    metadata,
                     op,
                     path,
                     expected,
                     got,
-                    Some(error_repr(&cause_ref)),
+                    Some(cause_obj.clone_ref(py).into_any()),
                 )?;
-                return Ok(err(Py::new(py, new_err)?.into()));
+                new_err.inherit_cause_exception(&cause_ref);
+                return err(py, Py::new(py, new_err)?.into());
             }
         };
 
-        let result_type = py.get_type::<ResultObj>();
-        if !out.is_instance(result_type.as_any())? {
-            return Err(PyTypeError::new_err(
-                "and_then_try callback must return Result",
-            ));
-        }
-        let out_ref: PyRef<'_, ResultObj> = out.extract()?;
-        Ok(clone_result(py, &out_ref))
+        returned_result(out, "and_then_try")
     }
 
-    fn is_ok_and(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<bool> {
+    fn is_ok_and(&self, f: Bound<'_, PyAny>) -> PyResult<bool> {
         if self.is_ok {
             let value = self.ok.as_ref().expect("ok value");
-            let result = f.call1((value.clone_ref(py),))?;
+            let result = f.call1((value,))?;
             result.is_truthy()
         } else {
             Ok(false)
         }
     }
 
-    fn is_err_and(&self, py: Python<'_>, f: Bound<'_, PyAny>) -> PyResult<bool> {
+    fn is_err_and(&self, f: Bound<'_, PyAny>) -> PyResult<bool> {
         if self.is_ok {
             Ok(false)
         } else {
             let value = self.err.as_ref().expect("err value");
-            let result = f.call1((value.clone_ref(py),))?;
+            let result = f.call1((value,))?;
             result.is_truthy()
         }
     }
 
-    fn flatten(&self, py: Python<'_>) -> PyResult<Self> {
-        if self.is_ok {
-            let value = self.ok.as_ref().expect("ok value");
-            let result_type = py.get_type::<ResultObj>();
-            if !value.bind(py).is_instance(result_type.as_any())? {
-                return Err(PyTypeError::new_err(
-                    "flatten requires Ok value to be a Result",
-                ));
-            }
-            let inner_ref: PyRef<'_, ResultObj> = value.extract(py)?;
-            Ok(clone_result(py, &inner_ref))
+    fn flatten(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        let py = slf.py();
+        let this = slf.get();
+        if this.is_ok {
+            let value = this.ok.as_ref().expect("ok value");
+            let inner = value
+                .bind(py)
+                .cast::<ResultObj>()
+                .map_err(|_| PyTypeError::new_err("flatten requires Ok value to be a Result"))?;
+            Ok(inner.clone().unbind())
         } else {
-            Ok(err(self.err.as_ref().expect("err value").clone_ref(py)))
+            Ok(slf.clone().unbind())
         }
     }
 
-    fn transpose(&self, py: Python<'_>) -> PyResult<OptionObj> {
-        if self.is_ok {
-            let value = self.ok.as_ref().expect("ok value");
+    fn transpose(slf: &Bound<'_, Self>) -> PyResult<Py<OptionObj>> {
+        let py = slf.py();
+        let this = slf.get();
+        if this.is_ok {
+            let value = this.ok.as_ref().expect("ok value");
             let option_type = py.get_type::<OptionObj>();
             if !value.bind(py).is_instance(option_type.as_any())? {
                 return Err(PyTypeError::new_err(
@@ -424,25 +356,21 @@ impl ResultObj {
             let opt_ref: PyRef<'_, OptionObj> = value.extract(py)?;
             if opt_ref.is_some {
                 let inner_value = opt_ref.value.as_ref().expect("some value").clone_ref(py);
-                let result_obj = ok(inner_value);
-                let py_result = Py::new(py, result_obj)?;
-                Ok(some(py_result.into()))
+                let result_obj = ok(py, inner_value);
+                let py_result = result_obj?;
+                some(py, py_result.into())
             } else {
-                Ok(none_())
+                none_(py)
             }
         } else {
-            let err_value = self.err.as_ref().expect("err value").clone_ref(py);
-            let result_obj = err(err_value);
-            let py_result = Py::new(py, result_obj)?;
-            Ok(some(py_result.into()))
+            some(py, slf.clone().into_any().unbind())
         }
     }
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (message, *, code = "context", metadata = None, op = None, path = None, expected = None, got = None))]
     fn context(
-        &self,
-        py: Python<'_>,
+        slf: &Bound<'_, Self>,
         message: &str,
         code: &str,
         metadata: Option<Py<PyAny>>,
@@ -450,13 +378,20 @@ impl ResultObj {
         path: Option<Py<PyAny>>,
         expected: Option<String>,
         got: Option<String>,
-    ) -> PyResult<Self> {
-        if self.is_ok {
-            return Ok(clone_result_value(py, self));
+    ) -> PyResult<Py<Self>> {
+        let py = slf.py();
+        let this = slf.get();
+        if this.is_ok {
+            return Ok(slf.clone().unbind());
         }
 
-        let err_value = self.err.as_ref().expect("err value").clone_ref(py);
-        let err_ref = err_value.bind(py).extract::<PyRef<'_, Error>>()?;
+        let err_ref = this
+            .err
+            .as_ref()
+            .expect("err value")
+            .bind(py)
+            .cast::<Error>()?
+            .get();
 
         let mut merged_metadata = err_ref.metadata.clone();
         let extra_metadata = extract_metadata(py, metadata)?;
@@ -476,28 +411,45 @@ impl ResultObj {
             path,
             expected: expected.or_else(|| err_ref.expected.clone()),
             got: got.or_else(|| err_ref.got.clone()),
-            cause: Some(error_repr(&err_ref)),
+            cause: Some(Arc::new(err_ref.clone())),
+            children: Vec::new(),
+            exception: None,
+            tracebacks: err_ref.tracebacks.clone(),
         };
-        Ok(err(Py::new(py, new_err)?.into()))
+        err(py, Py::new(py, new_err)?.into())
     }
 
-    fn with_code(&self, py: Python<'_>, code: &str) -> PyResult<Self> {
-        if self.is_ok {
-            return Ok(clone_result_value(py, self));
+    fn with_code(slf: &Bound<'_, Self>, code: &str) -> PyResult<Py<Self>> {
+        let py = slf.py();
+        let this = slf.get();
+        if this.is_ok {
+            return Ok(slf.clone().unbind());
         }
-        let err_value = self.err.as_ref().expect("err value").clone_ref(py);
-        let err_ref = err_value.bind(py).extract::<PyRef<'_, Error>>()?;
+        let err_ref = this
+            .err
+            .as_ref()
+            .expect("err value")
+            .bind(py)
+            .cast::<Error>()?
+            .get();
         let mut new_err = err_ref.clone();
         new_err.code = code.to_string();
-        Ok(err(Py::new(py, new_err)?.into()))
+        err(py, Py::new(py, new_err)?.into())
     }
 
-    fn map_err_code(&self, py: Python<'_>, prefix: &str) -> PyResult<Self> {
-        if self.is_ok {
-            return Ok(clone_result_value(py, self));
+    fn map_err_code(slf: &Bound<'_, Self>, prefix: &str) -> PyResult<Py<Self>> {
+        let py = slf.py();
+        let this = slf.get();
+        if this.is_ok {
+            return Ok(slf.clone().unbind());
         }
-        let err_value = self.err.as_ref().expect("err value").clone_ref(py);
-        let err_ref = err_value.bind(py).extract::<PyRef<'_, Error>>()?;
+        let err_ref = this
+            .err
+            .as_ref()
+            .expect("err value")
+            .bind(py)
+            .cast::<Error>()?
+            .get();
         let mut new_err = err_ref.clone();
         let prefix_dot = format!("{prefix}.");
         if new_err.code.is_empty() {
@@ -505,35 +457,185 @@ impl ResultObj {
         } else if !new_err.code.starts_with(&prefix_dot) {
             new_err.code = format!("{prefix}.{}", new_err.code);
         }
-        Ok(err(Py::new(py, new_err)?.into()))
+        err(py, Py::new(py, new_err)?.into())
     }
 
     #[classmethod]
-    #[pyo3(signature = (f, *exceptions))]
+    #[pyo3(signature = (f, *exceptions, keep_exception = false))]
     fn attempt(
         _cls: &Bound<'_, PyType>,
         py: Python<'_>,
         f: Bound<'_, PyAny>,
         exceptions: &Bound<'_, PyTuple>,
-    ) -> PyResult<Self> {
+        keep_exception: bool,
+    ) -> PyResult<Py<Self>> {
         match f.call0() {
-            Ok(value) => {
-                let result_type = py.get_type::<ResultObj>();
-                if value.is_instance(result_type.as_any())? {
-                    let out_ref: PyRef<'_, ResultObj> = value.extract()?;
-                    Ok(clone_result(py, &out_ref))
-                } else {
-                    Ok(ok(value.into()))
-                }
+            Ok(value) => result_or_ok(py, value),
+            Err(err) => match catch_exception(py, err, exceptions)? {
+                Caught::Convert(err) => error_from_exception(py, err, keep_exception),
+                Caught::Raise(err) => Err(err),
+            },
+        }
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (f, *exceptions, keep_exception = false))]
+    fn attempt_async(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        f: Bound<'_, PyAny>,
+        exceptions: Bound<'_, PyTuple>,
+        keep_exception: bool,
+    ) -> PyResult<AttemptAwaitable> {
+        let awaitable = if f.hasattr("__await__")? {
+            f
+        } else {
+            match f.call0() {
+                Ok(awaitable) => awaitable,
+                Err(err) => match catch_exception(py, err, &exceptions)? {
+                    Caught::Convert(err) => {
+                        return Ok(AttemptAwaitable {
+                            inner: None,
+                            ready: Some(error_from_exception(py, err, keep_exception)?.into_any()),
+                            exceptions: exceptions.unbind(),
+                            keep_exception,
+                        });
+                    }
+                    Caught::Raise(err) => return Err(err),
+                },
             }
-            Err(err) => {
-                if should_catch(py, &err, exceptions)? {
-                    Ok(error_from_exception(py, err))
-                } else {
-                    Err(err)
-                }
+        };
+        if !awaitable.hasattr("__await__")? {
+            return Err(PyTypeError::new_err(
+                "attempt_async expects an awaitable or a callable returning one",
+            ));
+        }
+        Ok(AttemptAwaitable {
+            inner: Some(await_iter(&awaitable)?.unbind()),
+            ready: None,
+            exceptions: exceptions.unbind(),
+            keep_exception,
+        })
+    }
+
+    /// Collect the Ok values of `results` into `into` (a list by default),
+    /// stopping at the first `Err`.
+    #[classmethod]
+    #[pyo3(signature = (results, *, into = None))]
+    fn collect(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        results: &Bound<'_, PyAny>,
+        into: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Self>> {
+        let values = PyList::empty(py);
+        for item in results.try_iter()? {
+            let item = item?;
+            let result = item
+                .cast::<ResultObj>()
+                .map_err(|_| PyTypeError::new_err("collect expects Result values"))?;
+            if !result.get().is_ok {
+                return Ok(result.clone().unbind());
             }
+            let result = result.get();
+            values.append(result.payload())?;
+        }
+        ok(py, collect_into(values, into.as_ref())?)
+    }
+
+    /// Split `results` into `(ok_values, errors)`, consuming the whole iterable.
+    #[classmethod]
+    fn partition<'py>(
+        _cls: &Bound<'py, PyType>,
+        py: Python<'py>,
+        results: &Bound<'py, PyAny>,
+    ) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyList>)> {
+        let oks = PyList::empty(py);
+        let errs = PyList::empty(py);
+        for item in results.try_iter()? {
+            let item = item?;
+            let result = item
+                .cast::<ResultObj>()
+                .map_err(|_| PyTypeError::new_err("partition expects Result values"))?
+                .borrow();
+            let target = if result.is_ok { &oks } else { &errs };
+            target.append(result.payload())?;
         }
+        Ok((oks, errs))
+    }
+
+    /// Like `collect`, but consumes every result and returns one `Err` whose
+    /// children are all the errors, each with its index prepended to `path`.
+    #[classmethod]
+    #[pyo3(signature = (results, *, code = "collect_all", message = None, into = None))]
+    fn collect_all(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        results: &Bound<'_, PyAny>,
+        code: &str,
+        message: Option<String>,
+        into: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Self>> {
+        let mut acc = Accumulator::new(py, "collect_all");
+        for item in results.try_iter()? {
+            acc.push(&item?)?;
+        }
+        acc.finish(code, message, |values| collect_into(values, into.as_ref()))
+    }
+
+    /// `Ok((a, b, ...))` when every argument is Ok, otherwise one `Err`
+    /// aggregating every failure (as with `collect_all`).
+    #[classmethod]
+    #[pyo3(signature = (*results))]
+    fn all(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        results: &Bound<'_, PyTuple>,
+    ) -> PyResult<Py<Self>> {
+        let mut acc = Accumulator::new(py, "all");
+        for item in results.iter() {
+            acc.push(&item)?;
+        }
+        acc.finish("all", None, |values| {
+            Ok(values.to_tuple().into_any().unbind())
+        })
+    }
+
+    /// `Ok(f(a, b, ...))` when every result is Ok, otherwise one `Err`
+    /// aggregating every failure; `f` is only called on success.
+    #[classmethod]
+    #[pyo3(signature = (f, *results))]
+    fn map_n(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        f: &Bound<'_, PyAny>,
+        results: &Bound<'_, PyTuple>,
+    ) -> PyResult<Py<Self>> {
+        let mut acc = Accumulator::new(py, "map_n");
+        for item in results.iter() {
+            acc.push(&item)?;
+        }
+        acc.finish("all", None, |values| {
+            Ok(f.call1(values.to_tuple())?.unbind())
+        })
+    }
+
+    /// `Ok((self, other))`, or one `Err` aggregating the errors of both sides.
+    fn zip(slf: &Bound<'_, Self>, other: &Bound<'_, ResultObj>) -> PyResult<Py<Self>> {
+        zip_accumulator(slf, other)?.finish("zip", None, |values| {
+            Ok(values.to_tuple().into_any().unbind())
+        })
+    }
+
+    /// `Ok(f(self, other))`, or one `Err` aggregating the errors of both sides.
+    fn zip_with(
+        slf: &Bound<'_, Self>,
+        other: &Bound<'_, ResultObj>,
+        f: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<Self>> {
+        zip_accumulator(slf, other)?.finish("zip", None, |values| {
+            Ok(f.call1(values.to_tuple())?.unbind())
+        })
     }
 
     fn unwrap_or_raise(&self, py: Python<'_>, exc: Py<PyAny>) -> PyResult<Py<PyAny>> {
@@ -550,22 +652,294 @@ impl ResultObj {
             Err(PyErr::from_value(exc_ref.clone()))
         }
     }
+
+    /// `{"ok": value}` or `{"err": error.to_dict()}`; `encoder` maps the Ok value.
+    #[pyo3(signature = (*, encoder = None))]
+    fn to_dict(&self, py: Python<'_>, encoder: Option<Bound<'_, PyAny>>) -> PyResult<Py<PyAny>> {
+        let dict = PyDict::new(py);
+        if self.is_ok {
+            let value = self.payload().bind(py);
+            match &encoder {
+                Some(encoder) => dict.set_item("ok", encoder.call1((value,))?)?,
+                None => dict.set_item("ok", value)?,
+            }
+        } else {
+            dict.set_item("err", self.error_payload(py)?.get().to_dict(py, false)?)?;
+        }
+        Ok(dict.into())
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (data, *, decoder = None))]
+    fn from_dict(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        data: &Bound<'_, PyAny>,
+        decoder: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Self>> {
+        let dict = data.cast_exact::<PyDict>()?;
+        if dict.len() == 1 {
+            if let Some(value) = dict.get_item("ok")? {
+                let value = match &decoder {
+                    Some(decoder) => decoder.call1((value,))?,
+                    None => value,
+                };
+                return ok(py, value.unbind());
+            }
+            if let Some(error) = dict.get_item("err")? {
+                return err(py, Py::new(py, error_from_dict(&error, false)?)?.into_any());
+            }
+        }
+        Err(PyValueError::new_err(
+            "Result dict must have exactly one of 'ok' or 'err'",
+        ))
+    }
+
+    /// Versioned JSON envelope: `{"schema_version": 1, "ok": ...}` or `{..., "err": {...}}`.
+    #[pyo3(signature = (*, encoder = None))]
+    fn to_json(&self, py: Python<'_>, encoder: Option<Bound<'_, PyAny>>) -> PyResult<String> {
+        let document = if self.is_ok {
+            envelope_document(
+                "ok",
+                encode_payload(self.payload().bind(py), encoder.as_ref())?,
+            )?
+        } else {
+            let error = self.error_payload(py)?;
+            envelope_document("err", Value::Object(error.get().to_json_object(false, 2)?))?
+        };
+        Ok(document.to_string())
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (data, *, decoder = None))]
+    fn from_json(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        data: &Bound<'_, PyAny>,
+        decoder: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Self>> {
+        let invalid = |msg: String| PyValueError::new_err(format!("invalid Result JSON: {msg}"));
+        let document = parse_json_document(data, "Result")?;
+        match envelope_variant(&document, ["ok", "err"]).map_err(invalid)? {
+            ("ok", payload) => ok(py, decode_payload(py, payload, decoder.as_ref())?.unbind()),
+            (_, Value::Object(object)) => {
+                let error = error_from_json_object(object, "err.", false).map_err(invalid)?;
+                err(py, Py::new(py, error)?.into_any())
+            }
+            _ => Err(invalid("'err' must be an object".to_string())),
+        }
+    }
+
+    fn __eq__(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        let Ok(other) = other.cast::<ResultObj>() else {
+            return Ok(py.NotImplemented());
+        };
+        let other = other.borrow();
+        let equal =
+            self.is_ok == other.is_ok && self.payload().bind(py).eq(other.payload().bind(py))?;
+        Ok(equal.into_pyobject(py)?.to_owned().into_any().unbind())
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        (self.is_ok, self.payload()).into_pyobject(py)?.hash()
+    }
+
+    // Results are frozen, so a cycle through one always passes through a mutable
+    // object (the payload's `__dict__`, a list, a frame) that the GC can clear;
+    // reporting the payload is enough and no `__clear__` is needed.
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.ok)?;
+        visit.call(&self.err)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let variant = if self.is_ok { "Ok" } else { "Err" };
+        Ok(format!("{}({})", variant, self.payload().bind(py).repr()?))
+    }
+
+    // `if result:` is almost always a forgotten `is_ok()`, so refuse to guess.
+    fn __bool__(&self) -> PyResult<bool> {
+        Err(PyTypeError::new_err(
+            "Result has no truth value; use is_ok() or is_err()",
+        ))
+    }
+
+    // Pickle through the public constructors: `Ok(value)` / `Err(error)`. `map_err` can
+    // leave a non-`Error` payload that `Err(...)` rejects; those go through `_err_unchecked`.
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<(Bound<'py, PyAny>, (Py<PyAny>,))> {
+        let payload = self.payload();
+        let constructor = if self.is_ok {
+            py.get_type::<OkObj>().into_any()
+        } else if payload.bind(py).is_instance_of::<Error>() {
+            py.get_type::<ErrObj>().into_any()
+        } else {
+            py.get_type::<ResultObj>().getattr("_err_unchecked")?
+        };
+        Ok((constructor, (payload.clone_ref(py),)))
+    }
+
+    /// `Err(payload)` for any payload; only used to unpickle `map_err` results.
+    #[classmethod]
+    fn _err_unchecked(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        payload: Py<PyAny>,
+    ) -> PyResult<Py<Self>> {
+        err(py, payload)
+    }
+
+    // Results are immutable, so a copy is the result itself.
+    fn __copy__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__(&self, py: Python<'_>, memo: Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let copied = py
+            .import("copy")?
+            .call_method1("deepcopy", (self.payload().clone_ref(py), memo))?
+            .unbind();
+        if self.is_ok {
+            ok(py, copied)
+        } else {
+            err(py, copied)
+        }
+    }
+}
+
+/// Awaitable returned by `Result.attempt_async`.
+///
+/// Resolves to `Ok(value)` (or the returned `Result` itself) and converts
+/// matching exceptions into `Err(Error)`. `asyncio.CancelledError` always
+/// propagates so task cancellation keeps working.
+#[pyclass(name = "AttemptAwaitable")]
+pub struct AttemptAwaitable {
+    inner: Option<Py<PyIterator>>,
+    ready: Option<Py<PyAny>>,
+    exceptions: Py<PyTuple>,
+    keep_exception: bool,
+}
+
+#[pymethods]
+impl AttemptAwaitable {
+    fn __await__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.resume(py, Resume::Send(py.None().into_bound(py)))
+    }
+
+    fn send(&mut self, py: Python<'_>, value: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        self.resume(py, Resume::Send(value))
+    }
+
+    #[pyo3(signature = (*args))]
+    fn throw(&mut self, py: Python<'_>, args: Bound<'_, PyTuple>) -> PyResult<Py<PyAny>> {
+        self.resume(py, Resume::Throw(args))
+    }
+
+    fn close(&mut self, py: Python<'_>) -> PyResult<()> {
+        self.ready = None;
+        if let Some(inner) = self.inner.take() {
+            inner.bind(py).call_method0("close")?;
+        }
+        Ok(())
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.inner)?;
+        visit.call(&self.ready)?;
+        visit.call(&self.exceptions)
+    }
+
+    fn __clear__(&mut self) {
+        self.inner = None;
+        self.ready = None;
+    }
+}
+
+impl AttemptAwaitable {
+    fn resume<'py>(&mut self, py: Python<'py>, action: Resume<'py>) -> PyResult<Py<PyAny>> {
+        if let Some(value) = self.ready.take() {
+            return Err(stop_iteration(value));
+        }
+        let Some(inner) = self.inner.as_ref().map(|i| i.bind(py).clone()) else {
+            return Err(reused_awaitable());
+        };
+        let outcome = match action {
+            Resume::Send(value) => inner.send(&value),
+            Resume::Throw(args) => throw_into(&inner, &args),
+        };
+        if let Ok(PySendResult::Next(value)) = outcome {
+            return Ok(value.unbind());
+        }
+        self.inner = None;
+        let result = match outcome {
+            Ok(PySendResult::Return(value)) => result_or_ok(py, value)?,
+            Err(err) if is_cancelled(py, &err)? => return Err(err),
+            Err(err) => match catch_exception(py, err, self.exceptions.bind(py))? {
+                Caught::Convert(err) => error_from_exception(py, err, self.keep_exception)?,
+                Caught::Raise(err) => return Err(err),
+            },
+            Ok(PySendResult::Next(_)) => unreachable!(),
+        };
+        Err(stop_iteration(result.into_any()))
+    }
 }
 
-// Python-facing constructor functions
-#[pyfunction(name = "Ok")]
-pub fn py_ok(value: Py<PyAny>) -> ResultObj {
-    ok(value)
+// Python-facing variant classes. `Ok(v)` / `Err(e)` construct them directly and
+// `match` class patterns bind the payload through `__match_args__`.
+#[pyclass(frozen, extends = ResultObj, name = "Ok", module = "pyropust")]
+pub struct OkObj;
+
+#[pymethods]
+impl OkObj {
+    #[classattr]
+    #[pyo3(name = "__match_args__")]
+    const MATCH_ARGS: (&'static str,) = ("value",);
+
+    #[new]
+    fn new(value: Py<PyAny>) -> (Self, ResultObj) {
+        (OkObj, ok_value(value))
+    }
+
+    #[getter]
+    fn value(slf: PyRef<'_, Self>, py: Python<'_>) -> Py<PyAny> {
+        slf.as_super().ok.as_ref().expect("ok value").clone_ref(py)
+    }
 }
 
-#[pyfunction(name = "Err")]
-pub fn py_err(py: Python<'_>, error: Py<PyAny>) -> PyResult<ResultObj> {
-    let error_ref = error.bind(py);
-    let error_type = py.get_type::<Error>();
-    if !error_ref.is_instance(error_type.as_any())? {
-        return Err(PyTypeError::new_err("Err expects Error"));
+#[pyclass(frozen, extends = ResultObj, name = "Err", module = "pyropust")]
+pub struct ErrObj;
+
+#[pymethods]
+impl ErrObj {
+    #[classattr]
+    #[pyo3(name = "__match_args__")]
+    const MATCH_ARGS: (&'static str,) = ("error",);
+
+    #[new]
+    fn new(py: Python<'_>, error: Py<PyAny>) -> PyResult<(Self, ResultObj)> {
+        let error_ref = error.bind(py);
+        let error_type = py.get_type::<Error>();
+        if !error_ref.is_instance(error_type.as_any())? {
+            return Err(PyTypeError::new_err("Err expects Error"));
+        }
+        Ok((ErrObj, err_value(error)))
+    }
+
+    #[getter]
+    fn error(slf: PyRef<'_, Self>, py: Python<'_>) -> Py<PyAny> {
+        slf.as_super()
+            .err
+            .as_ref()
+            .expect("err value")
+            .clone_ref(py)
     }
-    Ok(err(error))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -581,12 +955,12 @@ pub fn py_err_from_parts(
     path: Option<Py<PyAny>>,
     expected: Option<String>,
     got: Option<String>,
-    cause: Option<String>,
-) -> PyResult<ResultObj> {
+    cause: Option<Py<PyAny>>,
+) -> PyResult<Py<ResultObj>> {
     let error = build_error_from_parts(
         py, code, message, kind, metadata, op, path, expected, got, cause,
     )?;
-    Ok(err(Py::new(py, error)?.into()))
+    err(py, Py::new(py, error)?.into())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -602,12 +976,12 @@ pub fn py_bail_from_parts(
     path: Option<Py<PyAny>>,
     expected: Option<String>,
     got: Option<String>,
-    cause: Option<String>,
-) -> PyResult<ResultObj> {
+    cause: Option<Py<PyAny>>,
+) -> PyResult<Py<ResultObj>> {
     let error = build_error_from_parts(
         py, code, message, kind, metadata, op, path, expected, got, cause,
     )?;
-    Ok(err(Py::new(py, error)?.into()))
+    err(py, Py::new(py, error)?.into())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -624,20 +998,124 @@ pub fn py_ensure(
     path: Option<Py<PyAny>>,
     expected: Option<String>,
     got: Option<String>,
-    cause: Option<String>,
-) -> PyResult<ResultObj> {
+    cause: Option<Py<PyAny>>,
+) -> PyResult<Py<ResultObj>> {
     if condition.is_truthy()? {
-        Ok(ok(py.None()))
+        ok(py, py.None())
     } else {
         let error = build_error_from_parts(
             py, code, message, kind, metadata, op, path, expected, got, cause,
         )?;
-        Ok(err(Py::new(py, error)?.into()))
+        err(py, Py::new(py, error)?.into())
+    }
+}
+
+fn zip_accumulator<'py>(
+    slf: &Bound<'py, ResultObj>,
+    other: &Bound<'py, ResultObj>,
+) -> PyResult<Accumulator<'py>> {
+    let mut acc = Accumulator::new(slf.py(), "zip");
+    acc.push(slf.as_any())?;
+    acc.push(other.as_any())?;
+    Ok(acc)
+}
+
+/// Ok values and errors gathered by the error-accumulating combinators. Each
+/// error is recorded with its position prepended to `path`.
+struct Accumulator<'py> {
+    values: Bound<'py, PyList>,
+    errors: Vec<Error>,
+    total: usize,
+    method: &'static str,
+}
+
+impl<'py> Accumulator<'py> {
+    fn new(py: Python<'py>, method: &'static str) -> Self {
+        Accumulator {
+            values: PyList::empty(py),
+            errors: Vec::new(),
+            total: 0,
+            method,
+        }
+    }
+
+    fn push(&mut self, item: &Bound<'py, PyAny>) -> PyResult<()> {
+        let result = item
+            .cast::<ResultObj>()
+            .map_err(|_| PyTypeError::new_err(format!("{} expects Result values", self.method)))?
+            .borrow();
+        let index = self.total;
+        self.total += 1;
+        if result.is_ok {
+            return self.values.append(result.payload());
+        }
+        let mut error = result
+            .payload()
+            .bind(item.py())
+            .cast::<Error>()
+            .map_err(|_| {
+                PyTypeError::new_err(format!("{} expects Err(Error) values", self.method))
+            })?
+            .get()
+            .clone();
+        error.path.insert(0, PathItem::Index(index));
+        self.errors.push(error);
+        Ok(())
+    }
+
+    /// `Ok(on_ok(values))`, or an `Err` aggregating every recorded error as a child.
+    fn finish(
+        self,
+        code: &str,
+        message: Option<String>,
+        on_ok: impl FnOnce(Bound<'py, PyList>) -> PyResult<Py<PyAny>>,
+    ) -> PyResult<Py<ResultObj>> {
+        let py = self.values.py();
+        if self.errors.is_empty() {
+            return ok(py, on_ok(self.values)?);
+        }
+        let message = message
+            .unwrap_or_else(|| format!("{} of {} items failed", self.errors.len(), self.total));
+        let error = Error {
+            kind: ErrorKind::InvalidInput,
+            code: code.to_string(),
+            message,
+            metadata: Metadata::new(),
+            op: None,
+            path: Vec::new(),
+            expected: None,
+            got: None,
+            cause: None,
+            children: self.errors,
+            exception: None,
+            tracebacks: Vec::new(),
+        };
+        err(py, Py::new(py, error)?.into_any())
+    }
+}
+
+/// Convert collected values to the `into` container: a list by default, otherwise
+/// `into(values)` (e.g. `tuple`, or `dict` for key/value pairs).
+pub fn collect_into(
+    values: Bound<'_, PyList>,
+    into: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    match into {
+        Some(into) if !into.is(values.get_type()) => Ok(into.call1((values,))?.unbind()),
+        _ => Ok(values.into_any().unbind()),
     }
 }
 
 // Internal constructor functions
-pub fn ok(value: Py<PyAny>) -> ResultObj {
+pub fn ok(py: Python<'_>, value: Py<PyAny>) -> PyResult<Py<ResultObj>> {
+    ok_value(value).into_instance(py)
+}
+
+pub fn err(py: Python<'_>, error: Py<PyAny>) -> PyResult<Py<ResultObj>> {
+    err_value(error).into_instance(py)
+}
+
+fn ok_value(value: Py<PyAny>) -> ResultObj {
     ResultObj {
         is_ok: true,
         ok: Some(value),
@@ -645,7 +1123,7 @@ pub fn ok(value: Py<PyAny>) -> ResultObj {
     }
 }
 
-pub fn err(error: Py<PyAny>) -> ResultObj {
+fn err_value(error: Py<PyAny>) -> ResultObj {
     ResultObj {
         is_ok: false,
         ok: None,
@@ -653,64 +1131,112 @@ pub fn err(error: Py<PyAny>) -> ResultObj {
     }
 }
 
-fn clone_result(py: Python<'_>, out_ref: &PyRef<'_, ResultObj>) -> ResultObj {
-    ResultObj {
-        is_ok: out_ref.is_ok,
-        ok: out_ref.ok.as_ref().map(|v| v.clone_ref(py)),
-        err: out_ref.err.as_ref().map(|v| v.clone_ref(py)),
+impl ResultObj {
+    /// The Ok value or the Err error, whichever this variant holds.
+    fn payload(&self) -> &Py<PyAny> {
+        if self.is_ok {
+            self.ok.as_ref().expect("ok value")
+        } else {
+            self.err.as_ref().expect("err value")
+        }
     }
-}
 
-fn clone_result_value(py: Python<'_>, out_ref: &ResultObj) -> ResultObj {
-    ResultObj {
-        is_ok: out_ref.is_ok,
-        ok: out_ref.ok.as_ref().map(|v| v.clone_ref(py)),
-        err: out_ref.err.as_ref().map(|v| v.clone_ref(py)),
+    /// The Err payload as an `Error`, for serialization.
+    fn error_payload<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, Error>> {
+        self.payload()
+            .bind(py)
+            .cast::<Error>()
+            .cloned()
+            .map_err(|_| PyTypeError::new_err("only Err(Error) can be serialized"))
+    }
+
+    /// Allocate as the `Ok`/`Err` subclass matching the variant.
+    pub fn into_instance(self, py: Python<'_>) -> PyResult<Py<ResultObj>> {
+        let is_ok = self.is_ok;
+        let init = PyClassInitializer::from(self);
+        let obj = if is_ok {
+            Bound::new(py, init.add_subclass(OkObj))?.into_super()
+        } else {
+            Bound::new(py, init.add_subclass(ErrObj))?.into_super()
+        };
+        Ok(obj.unbind())
     }
 }
 
-fn error_repr(err: &Error) -> String {
-    format!(
-        "Error(kind=ErrorKind.{}, code='{}', message='{}')",
-        err.kind.as_str(),
-        err.code,
-        err.message
-    )
+/// A `Result` returned by a callback, passed through as is.
+fn returned_result(out: Bound<'_, PyAny>, method: &str) -> PyResult<Py<ResultObj>> {
+    out.cast_into::<ResultObj>()
+        .map(Bound::unbind)
+        .map_err(|_| PyTypeError::new_err(format!("{method} callback must return Result")))
+}
+
+/// What an exception boundary does with a raised exception.
+enum Caught {
+    /// Convert into `Err(Error)`.
+    Convert(PyErr),
+    /// Propagate: an unmatched exception, or the unmatched part of a group.
+    Raise(PyErr),
 }
 
-fn should_catch(py: Python<'_>, err: &PyErr, exceptions: &Bound<'_, PyTuple>) -> PyResult<bool> {
-    if exceptions.is_empty() {
-        let base_exc = py.get_type::<pyo3::exceptions::PyException>();
-        return Ok(err.matches(py, base_exc.as_any()).unwrap());
+/// Match `err` against `exceptions` (default: `Exception`). Exception groups follow
+/// `except*`: the matching sub-exceptions are converted and any remainder is re-raised.
+fn catch_exception(
+    py: Python<'_>,
+    err: PyErr,
+    exceptions: &Bound<'_, PyTuple>,
+) -> PyResult<Caught> {
+    let types = if exceptions.is_empty() {
+        PyTuple::new(py, [py.get_type::<PyException>()])?
+    } else {
+        exceptions.clone()
+    };
+    let value = err.value(py);
+    // Asking for a group type explicitly catches the whole group as one exception.
+    if value.is_instance_of::<PyBaseExceptionGroup>() && !has_group_type(&types)? {
+        let (matched, rest): (Bound<'_, PyAny>, Bound<'_, PyAny>) =
+            value.call_method1("split", (types,))?.extract()?;
+        return Ok(if matched.is_none() {
+            Caught::Raise(err)
+        } else if !rest.is_none() {
+            Caught::Raise(PyErr::from_value(rest))
+        } else {
+            Caught::Convert(PyErr::from_value(matched))
+        });
     }
-    for exc in exceptions.iter() {
-        if err.matches(py, exc)? {
-            return Ok(true);
+    Ok(if err.matches(py, types)? {
+        Caught::Convert(err)
+    } else {
+        Caught::Raise(err)
+    })
+}
+
+fn has_group_type(types: &Bound<'_, PyTuple>) -> PyResult<bool> {
+    let group_type = types.py().get_type::<PyBaseExceptionGroup>();
+    for exc_type in types.iter() {
+        if let Ok(exc_type) = exc_type.cast::<PyType>() {
+            if exc_type.is_subclass(&group_type)? {
+                return Ok(true);
+            }
         }
     }
     Ok(false)
 }
 
-fn error_from_exception(py: Python<'_>, py_err: PyErr) -> ResultObj {
-    let err_obj = build_error_from_pyerr(py, py_err, "py_exception");
-    err(err_obj.into())
+// Returned Results pass through unchanged; any other value is wrapped in Ok.
+fn result_or_ok(py: Python<'_>, value: Bound<'_, PyAny>) -> PyResult<Py<ResultObj>> {
+    match value.cast_into::<ResultObj>() {
+        Ok(result) => Ok(result.unbind()),
+        Err(value) => ok(py, value.into_inner().unbind()),
+    }
 }
 
-fn extract_metadata(
+fn error_from_exception(
     py: Python<'_>,
-    metadata: Option<Py<PyAny>>,
-) -> PyResult<HashMap<String, String>> {
-    let mut data = HashMap::new();
-    let Some(meta_value) = metadata else {
-        return Ok(data);
-    };
-    let meta_dict = meta_value.bind(py).cast_exact::<PyDict>()?;
-    for (k, v) in meta_dict.iter() {
-        let key = k.extract::<String>()?;
-        let value = v.extract::<String>()?;
-        data.insert(key, value);
-    }
-    Ok(data)
+    py_err: PyErr,
+    keep_exception: bool,
+) -> PyResult<Py<ResultObj>> {
+    let err_obj = build_error_from_pyerr(py, py_err, "py_exception", keep_exception);
+    err(py, err_obj.into())
 }
 
 fn extract_path(py: Python<'_>, path: Py<PyAny>) -> PyResult<Vec<PathItem>> {
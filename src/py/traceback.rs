@@ -0,0 +1,265 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use pyo3::exceptions::PyBaseException;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTraceback};
+use pyo3::{PyTraverseError, PyVisit};
+
+static CAPTURE_TRACEBACKS: AtomicBool = AtomicBool::new(true);
+/// Maximum number of frames kept per traceback; `usize::MAX` means no limit.
+static TRACEBACK_LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Enable or disable traceback capture for converted exceptions, process-wide.
+#[pyfunction]
+#[pyo3(signature = (*, enabled))]
+pub fn set_traceback_capture(enabled: bool) {
+    CAPTURE_TRACEBACKS.store(enabled, Ordering::Relaxed);
+}
+
+#[pyfunction]
+pub fn traceback_capture_enabled() -> bool {
+    CAPTURE_TRACEBACKS.load(Ordering::Relaxed)
+}
+
+/// Keep only the `limit` most recent frames of captured tracebacks (`None`: all).
+#[pyfunction]
+pub fn set_traceback_limit(limit: Option<usize>) {
+    TRACEBACK_LIMIT.store(limit.unwrap_or(usize::MAX), Ordering::Relaxed);
+}
+
+#[pyfunction]
+pub fn traceback_limit() -> Option<usize> {
+    match TRACEBACK_LIMIT.load(Ordering::Relaxed) {
+        usize::MAX => None,
+        limit => Some(limit),
+    }
+}
+
+/// One entry of a captured traceback.
+#[pyclass(frozen, eq, hash, name = "Frame", module = "pyropust")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Frame {
+    filename: String,
+    lineno: usize,
+    function: String,
+    line: Option<String>,
+}
+
+#[pymethods]
+impl Frame {
+    #[getter]
+    fn filename(&self) -> String {
+        self.filename.clone()
+    }
+
+    #[getter]
+    fn lineno(&self) -> usize {
+        self.lineno
+    }
+
+    #[getter]
+    fn function(&self) -> String {
+        self.function.clone()
+    }
+
+    /// The stripped source line, or `None` when the source is unavailable.
+    #[getter]
+    fn line(&self) -> Option<String> {
+        self.line.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Frame(filename='{}', lineno={}, function='{}')",
+            self.filename, self.lineno, self.function
+        )
+    }
+}
+
+/// A traceback captured when an exception is converted. Formatting (with
+/// `traceback.format_exception`) and frame extraction happen the first time
+/// either is read.
+pub struct LazyTraceback {
+    state: Mutex<State>,
+}
+
+enum State {
+    Pending {
+        exception: Py<PyBaseException>,
+        traceback: Option<Py<PyTraceback>>,
+        chain: bool,
+        limit: Option<usize>,
+    },
+    Resolved(Arc<Resolved>),
+}
+
+struct Resolved {
+    text: Option<String>,
+    frames: Vec<Frame>,
+}
+
+impl LazyTraceback {
+    /// `None` when traceback capture is disabled.
+    pub fn capture(exception: &Bound<'_, PyBaseException>, chain: bool) -> Option<Self> {
+        if !traceback_capture_enabled() {
+            return None;
+        }
+        let state = State::Pending {
+            exception: exception.clone().unbind(),
+            traceback: exception
+                .getattr("__traceback__")
+                .ok()
+                .and_then(|traceback| traceback.cast_into::<PyTraceback>().ok())
+                .map(Bound::unbind),
+            chain,
+            limit: traceback_limit(),
+        };
+        Some(LazyTraceback {
+            state: Mutex::new(state),
+        })
+    }
+
+    /// The formatted traceback.
+    pub fn text(&self, py: Python<'_>) -> Option<String> {
+        self.resolve(py).text.clone()
+    }
+
+    /// The traceback's frames, outermost first, without pyropust's own frames.
+    pub fn frames(&self, py: Python<'_>) -> Vec<Frame> {
+        self.resolve(py).frames.clone()
+    }
+
+    /// Format and extract frames on first use, then release the traceback.
+    fn resolve(&self, py: Python<'_>) -> Arc<Resolved> {
+        // Resolve without holding the lock: formatting runs Python code, which may
+        // release the GIL or read this traceback again.
+        let (exception, traceback, chain, limit) = match &*self.lock() {
+            State::Resolved(resolved) => return resolved.clone(),
+            State::Pending {
+                exception,
+                traceback,
+                chain,
+                limit,
+            } => (
+                exception.clone_ref(py),
+                traceback.as_ref().map(|tb| tb.bind(py).clone()),
+                *chain,
+                *limit,
+            ),
+        };
+        let resolved = Arc::new(Resolved {
+            text: format_exception(exception.bind(py), traceback.as_ref(), chain, limit),
+            frames: extract_frames(py, traceback, limit).unwrap_or_default(),
+        });
+        let mut state = self.lock();
+        if let State::Resolved(resolved) = &*state {
+            return resolved.clone();
+        }
+        *state = State::Resolved(resolved.clone());
+        resolved
+    }
+
+    /// Report the still-unformatted exception and traceback to the GC. Traversal
+    /// must not block, so a traceback being resolved right now is skipped.
+    pub fn traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        let Ok(state) = self.state.try_lock() else {
+            return Ok(());
+        };
+        if let State::Pending {
+            exception,
+            traceback,
+            ..
+        } = &*state
+        {
+            visit.call(exception)?;
+            visit.call(traceback)?;
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn format_exception(
+    exception: &Bound<'_, PyBaseException>,
+    traceback: Option<&Bound<'_, PyTraceback>>,
+    chain: bool,
+    limit: Option<usize>,
+) -> Option<String> {
+    let py = exception.py();
+    let traceback_mod = py.import("traceback").ok()?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("chain", chain).ok()?;
+    if let Some(limit) = limit {
+        // A negative limit keeps the most recent frames.
+        kwargs.set_item("limit", -(limit as i64)).ok()?;
+    }
+    let formatted = traceback_mod
+        .call_method(
+            "format_exception",
+            (exception.get_type(), exception, traceback),
+            Some(&kwargs),
+        )
+        .ok()?
+        .extract::<Vec<String>>()
+        .ok()?;
+    Some(formatted.concat())
+}
+
+/// Walk `tb_next` from the outermost entry, skipping frames that run pyropust's own
+/// Python code (e.g. the `@catch` wrapper), and keep the `limit` most recent.
+fn extract_frames(
+    py: Python<'_>,
+    traceback: Option<Bound<'_, PyTraceback>>,
+    limit: Option<usize>,
+) -> PyResult<Vec<Frame>> {
+    let mut entries = Vec::new();
+    let mut current = traceback;
+    while let Some(tb) = current {
+        let frame = tb.getattr("tb_frame")?;
+        if !is_internal_frame(&frame)? {
+            let code = frame.getattr("f_code")?;
+            let filename = code.getattr("co_filename")?.extract::<String>()?;
+            let function = code.getattr("co_name")?.extract::<String>()?;
+            let lineno = tb.getattr("tb_lineno")?.extract::<Option<usize>>()?;
+            entries.push((filename, lineno.unwrap_or(0), function));
+        }
+        current = tb.getattr("tb_next")?.cast_into::<PyTraceback>().ok();
+    }
+    if let Some(limit) = limit {
+        entries.drain(..entries.len().saturating_sub(limit));
+    }
+
+    let linecache = py.import("linecache")?;
+    entries
+        .into_iter()
+        .map(|(filename, lineno, function)| {
+            let line = linecache
+                .call_method1("getline", (&filename, lineno))?
+                .extract::<String>()?;
+            let line = line.trim();
+            Ok(Frame {
+                filename,
+                lineno,
+                function,
+                line: (!line.is_empty()).then(|| line.to_string()),
+            })
+        })
+        .collect()
+}
+
+fn is_internal_frame(frame: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let module = frame.getattr("f_globals")?.get_item("__name__").ok();
+    Ok(match module {
+        Some(module) => {
+            let name = module.extract::<String>().unwrap_or_default();
+            name == "pyropust" || name.starts_with("pyropust.")
+        }
+        None => false,
+    })
+}
@@ -98,8 +98,8 @@ def test_exception_to_error() -> None:
     except ValueError as e:
         error = exception_to_error(e, code=Code.INVALID)
         assert error.code == Code.INVALID
-        assert error.cause is not None
-        assert "invalid literal" in error.cause
+        assert error.cause is None
+        assert "invalid literal" in error.message
 
 
 def test_ensure_equivalent_to_if_err() -> None:
@@ -0,0 +1,134 @@
+"""Tests for structured Error cause chains (cause, chain, root_cause)."""
+
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+from pyropust import Error, Ok, Result, Some, err
+from tests.support import SampleCode, new_error, wrap_error
+
+
+def layered() -> Error:
+    base = new_error(code=SampleCode.NOT_FOUND, message="row missing", path=["users", 3])
+    repo = wrap_error(base, code=SampleCode.DB_FAIL, message="query failed")
+    return wrap_error(repo, code=SampleCode.ERROR, message="load user failed")
+
+
+class TestErrorCause:
+    """Test that wrapping keeps the inner Error."""
+
+    def test_wrap_keeps_structured_cause(self) -> None:
+        error = layered()
+        assert error.cause is not None
+        assert error.cause.code == SampleCode.DB_FAIL
+        assert error.cause.cause is not None
+        assert error.cause.cause.path == ["users", 3]
+
+    def test_context_keeps_structured_cause(self) -> None:
+        res: Result[int] = err(SampleCode.NOT_FOUND, "missing", metadata={"id": "7"})
+        wrapped = res.context("lookup failed").context("request failed")
+        root = wrapped.unwrap_err().root_cause()
+        assert root.code == SampleCode.NOT_FOUND
+        assert root.metadata["id"] == "7"
+
+    def test_map_try_cause_is_exception_error(self) -> None:
+        res = Ok("x").map_try(int, code=SampleCode.PARSE_ERROR, message="bad int")
+        cause = res.unwrap_err().cause
+        assert cause is not None
+        assert cause.code == "py_exception"
+        assert cause.metadata["exception"] == "ValueError"
+
+    def test_cause_keyword_accepts_error_and_str(self) -> None:
+        inner = new_error(code=SampleCode.BOOM, message="inner")
+        with_error = err(SampleCode.ERROR, "outer", cause=inner).unwrap_err()
+        assert with_error.cause == inner
+
+        with_str = err(SampleCode.ERROR, "outer", cause="disk full").unwrap_err()
+        assert with_str.cause is not None
+        assert with_str.cause.message == "disk full"
+
+        from_option = Some(1).ok_or(SampleCode.ERROR, "unused", cause=inner)
+        assert from_option.is_ok()
+
+    def test_cause_keyword_rejects_other_types(self) -> None:
+        with pytest.raises(TypeError, match="cause must be an Error or str"):
+            err(SampleCode.ERROR, "outer", cause=1)  # type: ignore[arg-type]
+
+
+class TestErrorChain:
+    """Test Error.chain() and Error.root_cause()."""
+
+    def test_chain_is_outermost_first(self) -> None:
+        error = layered()
+        codes = [layer.code for layer in error.chain()]
+        assert codes == [SampleCode.ERROR, SampleCode.DB_FAIL, SampleCode.NOT_FOUND]
+
+    def test_chain_starts_with_self(self) -> None:
+        error = layered()
+        assert next(error.chain()) is error
+
+    def test_root_cause(self) -> None:
+        assert layered().root_cause().message == "row missing"
+
+    def test_root_cause_without_cause_is_self(self) -> None:
+        error = new_error(code=SampleCode.ERROR, message="alone")
+        assert error.root_cause() is error
+        assert list(error.chain()) == [error]
+
+
+class TestCauseSerialization:
+    """Test nested cause representation in to_dict/from_dict."""
+
+    def test_to_dict_nests_causes(self) -> None:
+        data = layered().to_dict()
+        cause = data["cause"]
+        assert isinstance(cause, dict)
+        assert cause["code"] == SampleCode.DB_FAIL
+        assert isinstance(cause["cause"], dict)
+        assert cause["cause"]["path"] == ["users", 3]
+
+    def test_roundtrip_keeps_chain(self) -> None:
+        error = layered()
+        assert Error.from_dict(error.to_dict()) == error
+
+    def test_from_dict_accepts_legacy_string_cause(self) -> None:
+        error = Error.from_dict(
+            {"kind": "Internal", "code": "outer", "message": "m", "cause": "inner text"}
+        )
+        assert error.cause is not None
+        assert error.cause.message == "inner text"
+
+
+class TestDeepChain:
+    """Test that long chains are cheap to build, read and serialize."""
+
+    DEPTH = 20_000
+
+    def deep(self) -> Error:
+        res: Result[int] = err(SampleCode.NOT_FOUND, "missing")
+        for index in range(self.DEPTH):
+            res = res.context(f"layer {index}")
+        return res.unwrap_err()
+
+    def test_context_and_getters(self) -> None:
+        error = self.deep()
+        assert error.cause is not None
+        assert error.cause.message == f"layer {self.DEPTH - 2}"
+        assert error.root_cause().code == SampleCode.NOT_FOUND
+        assert sum(1 for _ in error.chain()) == self.DEPTH + 1
+
+    def test_equality_and_hash(self) -> None:
+        error = self.deep()
+        other = self.deep()
+        assert error == other
+        assert hash(error) == hash(other)
+
+    def test_dict_roundtrip(self) -> None:
+        error = self.deep()
+        assert Error.from_dict(error.to_dict()) == error
+
+    def test_pickle_roundtrip(self) -> None:
+        error = self.deep()
+        assert pickle.loads(pickle.dumps(error)) == error
@@ -0,0 +1,78 @@
+"""Tests for Error equality and hashing (__eq__, __hash__)."""
+
+from __future__ import annotations
+
+from pyropust import Error, ErrorKind, Result
+from tests.support import SampleCode, err_msg, new_error
+
+
+class TestErrorEquality:
+    """Test structural equality of Error."""
+
+    def test_equal_when_all_fields_match(self) -> None:
+        a = new_error(code=SampleCode.ERROR, message="boom", path=["a", 0], metadata={"k": "v"})
+        b = new_error(code=SampleCode.ERROR, message="boom", path=["a", 0], metadata={"k": "v"})
+        assert a == b
+
+    def test_compares_against_expected_value(self) -> None:
+        res: Result[int] = err_msg("boom")
+        assert res.unwrap_err() == Error.new(code=SampleCode.ERROR, message="boom")
+
+    def test_not_equal_when_any_field_differs(self) -> None:
+        base = new_error(code=SampleCode.ERROR, message="boom")
+        assert base != new_error(code=SampleCode.CUSTOM, message="boom")
+        assert base != new_error(code=SampleCode.ERROR, message="other")
+        assert base != new_error(code=SampleCode.ERROR, message="boom", kind=ErrorKind.NotFound)
+        assert base != new_error(code=SampleCode.ERROR, message="boom", path=["a"])
+        assert base != new_error(code=SampleCode.ERROR, message="boom", metadata={"k": "v"})
+
+    def test_numbers_compare_by_value(self) -> None:
+        a = new_error(code=SampleCode.ERROR, message="boom", metadata={"n": 1, "xs": [2, {"y": 3}]})
+        b = new_error(
+            code=SampleCode.ERROR, message="boom", metadata={"n": 1.0, "xs": [2.0, {"y": 3.0}]}
+        )
+        assert a == b
+        assert hash(a) == hash(b)
+        assert a != new_error(code=SampleCode.ERROR, message="boom", metadata={"n": 1.5})
+
+    def test_booleans_are_not_numbers(self) -> None:
+        # JSON keeps booleans apart from numbers, unlike Python's `True == 1`.
+        flag = new_error(code=SampleCode.ERROR, message="boom", metadata={"n": True})
+        assert flag != new_error(code=SampleCode.ERROR, message="boom", metadata={"n": 1})
+
+    def test_tracebacks_are_not_compared(self) -> None:
+        a = new_error(code=SampleCode.ERROR, message="boom", metadata={"py_traceback": "a"})
+        b = new_error(code=SampleCode.ERROR, message="boom", metadata={"py_traceback": "b"})
+        assert a == b
+        assert hash(a) == hash(b)
+
+    def test_not_equal_to_other_types(self) -> None:
+        assert new_error(code=SampleCode.ERROR, message="boom") != "boom"
+
+
+class TestErrorHash:
+    """Test Error hashing."""
+
+    def test_equal_errors_hash_equal(self) -> None:
+        a = new_error(code=SampleCode.ERROR, message="boom", metadata={"a": "1", "b": "2"})
+        b = new_error(code=SampleCode.ERROR, message="boom", metadata={"b": "2", "a": "1"})
+        assert hash(a) == hash(b)
+
+    def test_deduplicates_in_sets(self) -> None:
+        errors = {
+            new_error(code=SampleCode.ERROR, message="boom"),
+            new_error(code=SampleCode.ERROR, message="boom"),
+            new_error(code=SampleCode.ERROR, message="other"),
+        }
+        assert len(errors) == 2
+
+    def test_usable_as_dict_keys(self) -> None:
+        counts: dict[Error, int] = {}
+        for message in ["a", "b", "a"]:
+            key = new_error(code=SampleCode.ERROR, message=message)
+            counts[key] = counts.get(key, 0) + 1
+        assert counts[new_error(code=SampleCode.ERROR, message="a")] == 2
+
+    def test_error_kind_is_hashable(self) -> None:
+        assert hash(ErrorKind.NotFound) == hash(ErrorKind.NotFound)
+        assert len({ErrorKind.NotFound, ErrorKind.NotFound, ErrorKind.Internal}) == 2
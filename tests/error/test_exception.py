@@ -0,0 +1,113 @@
+"""Tests for retaining the originating exception (Error.exception, Error.reraise)."""
+
+from __future__ import annotations
+
+import asyncio
+import pickle
+import traceback
+
+import pytest
+
+from pyropust import Error, PyropustError, Result, catch, exception_to_error
+from tests.support import SampleCode, new_error, wrap_error
+
+
+class QuotaError(Exception):
+    def __init__(self, remaining: int) -> None:
+        super().__init__(f"quota exceeded ({remaining} left)")
+        self.remaining = remaining
+
+
+def missing_quota() -> None:
+    raise KeyError("quota")
+
+
+def raise_quota() -> int:
+    try:
+        missing_quota()
+    except KeyError as exc:
+        raise QuotaError(3) from exc
+
+
+def captured() -> QuotaError:
+    try:
+        raise_quota()
+    except QuotaError as exc:
+        return exc
+    pytest.fail("raise_quota() did not raise")
+
+
+class TestErrorException:
+    """Test the opt-in Error.exception reference."""
+
+    def test_not_retained_by_default(self) -> None:
+        assert exception_to_error(captured()).exception is None
+        assert Result.attempt(raise_quota).unwrap_err().exception is None
+
+    def test_exception_to_error_keeps_the_object(self) -> None:
+        exc = captured()
+        err = exception_to_error(exc, keep_exception=True)
+        assert err.exception is exc
+        assert isinstance(err.exception, QuotaError)
+        assert err.exception.remaining == 3
+        assert isinstance(err.exception.__cause__, KeyError)
+
+    def test_attempt_and_catch_keep_the_object(self) -> None:
+        err = Result.attempt(raise_quota, keep_exception=True).unwrap_err()
+        assert isinstance(err.exception, QuotaError)
+
+        wrapped = catch(QuotaError, keep_exception=True)(raise_quota)
+        assert isinstance(wrapped().unwrap_err().exception, QuotaError)
+
+    def test_async_boundaries_keep_the_object(self) -> None:
+        async def fail() -> int:
+            return raise_quota()
+
+        err = asyncio.run(Result.attempt_async(fail, keep_exception=True)).unwrap_err()
+        assert isinstance(err.exception, QuotaError)
+
+        wrapped = catch(QuotaError, keep_exception=True)(fail)
+        assert isinstance(asyncio.run(wrapped()).unwrap_err().exception, QuotaError)
+
+    def test_wrap_keeps_the_object_on_the_cause(self) -> None:
+        exc = captured()
+        err = wrap_error(exc, code=SampleCode.CUSTOM, message="outer")
+        assert err.cause is not None
+        assert err.cause.exception is None
+
+        kept = Error.wrap(exc, code=SampleCode.CUSTOM, message="outer", keep_exception=True)
+        assert kept.exception is None
+        assert kept.cause is not None
+        assert kept.cause.exception is exc
+
+    def test_ignored_by_equality_and_serialization(self) -> None:
+        exc = captured()
+        kept = exception_to_error(exc, keep_exception=True)
+        plain = exception_to_error(exc)
+        assert kept == plain
+        assert hash(kept) == hash(plain)
+        assert pickle.loads(pickle.dumps(kept)).exception is None
+
+
+class TestErrorReraise:
+    """Test Error.reraise()."""
+
+    def test_reraises_the_original_exception_with_traceback(self) -> None:
+        exc = captured()
+        err = exception_to_error(exc, keep_exception=True)
+        with pytest.raises(QuotaError) as exc_info:
+            err.reraise()
+        assert exc_info.value is exc
+        frames = [frame.name for frame in traceback.extract_tb(exc_info.value.__traceback__)]
+        assert "raise_quota" in frames
+
+    def test_reraise_searches_the_cause_chain(self) -> None:
+        err = Result.attempt(raise_quota, keep_exception=True).context("loading quota")
+        with pytest.raises(QuotaError):
+            err.unwrap_err().reraise()
+
+    def test_reraise_without_exception_raises_pyropust_error(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom")
+        with pytest.raises(PyropustError, match="boom") as exc_info:
+            err.reraise()
+        assert exc_info.value.error is err
@@ -0,0 +1,147 @@
+"""Tests for converting chained exceptions and notes into Error chains."""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+
+from pyropust import Error, Result, exception_to_error
+from tests.support import SampleCode, wrap_error
+
+
+def missing_port() -> None:
+    raise KeyError("port")
+
+
+def raise_from() -> None:
+    try:
+        missing_port()
+    except KeyError as exc:
+        raise ValueError("bad config") from exc
+
+
+def raise_during_handling() -> None:
+    try:
+        missing_port()
+    except KeyError:
+        raise ValueError("bad config")  # noqa: B904
+
+
+def raise_from_none() -> None:
+    try:
+        missing_port()
+    except KeyError:
+        raise ValueError("bad config") from None
+
+
+def captured(fn: Callable[[], None]) -> ValueError:
+    try:
+        fn()
+    except ValueError as exc:
+        return exc
+    raise AssertionError("function did not raise")
+
+
+class TestExceptionChain:
+    """Test __cause__ / __context__ translation."""
+
+    def test_explicit_cause_becomes_error_cause(self) -> None:
+        err = exception_to_error(captured(raise_from))
+        assert err.message == "ValueError: bad config"
+        assert err.metadata["exception_chain"] == "cause"
+        assert err.cause is not None
+        assert err.cause.metadata["exception"] == "KeyError"
+        assert err.cause.code == "py_exception"
+        assert err.cause.cause is None
+
+    def test_implicit_context_becomes_error_cause(self) -> None:
+        err = Result.attempt(raise_during_handling).unwrap_err()
+        assert err.metadata["exception_chain"] == "context"
+        assert err.cause is not None
+        assert err.cause.metadata["exception"] == "KeyError"
+
+    def test_suppressed_context_is_dropped(self) -> None:
+        err = exception_to_error(captured(raise_from_none))
+        assert err.cause is None
+        assert "exception_chain" not in err.metadata
+
+    def test_unchained_exception_has_no_cause(self) -> None:
+        err = exception_to_error(ValueError("plain"))
+        assert err.cause is None
+        assert "exception_chain" not in err.metadata
+
+    def test_custom_code_applies_to_outermost_only(self) -> None:
+        err = exception_to_error(captured(raise_from), code="config")
+        assert [layer.code for layer in err.chain()] == ["config", "py_exception"]
+
+    def test_full_traceback_on_outermost_only(self) -> None:
+        err = exception_to_error(captured(raise_from))
+        assert err.cause is not None
+        outer = err.metadata["py_traceback"]
+        inner = err.cause.metadata["py_traceback"]
+        assert isinstance(outer, str)
+        assert isinstance(inner, str)
+        assert "KeyError" in outer
+        assert "ValueError" not in inner
+
+    def test_cyclic_context_terminates(self) -> None:
+        first = ValueError("first")
+        second = KeyError("second")
+        first.__context__ = second
+        second.__context__ = first
+        err = exception_to_error(first)
+        assert [layer.metadata["exception"] for layer in err.chain()] == ["ValueError", "KeyError"]
+
+    def test_long_chain_is_truncated(self) -> None:
+        exc = ValueError("link 0")
+        for index in range(1, 10_000):
+            link = ValueError(f"link {index}")
+            link.__context__ = exc
+            exc = link
+        err = exception_to_error(exc)
+        layers = list(err.chain())
+        assert len(layers) == 100
+        assert layers[-1].message == "ValueError: link 9900"
+        assert layers[-1].metadata["exception_chain_truncated"] is True
+        assert "exception_chain_truncated" not in layers[-2].metadata
+        assert Error.from_json(err.to_json()) == err
+        assert hash(err) == hash(exception_to_error(exc))
+
+    def test_short_chain_is_not_marked_truncated(self) -> None:
+        err = exception_to_error(captured(raise_from))
+        assert all("exception_chain_truncated" not in layer.metadata for layer in err.chain())
+
+    def test_wrap_keeps_the_converted_chain(self) -> None:
+        err = wrap_error(captured(raise_from), code=SampleCode.CUSTOM, message="outer")
+        assert [layer.metadata.get("exception") for layer in err.chain()] == [
+            None,
+            "ValueError",
+            "KeyError",
+        ]
+
+    def test_chain_round_trips_through_json(self) -> None:
+        err = exception_to_error(captured(raise_from))
+        assert Error.from_json(err.to_json()) == err
+
+
+class TestExceptionNotes:
+    """Test PEP 678 __notes__ translation."""
+
+    def test_notes_become_metadata(self) -> None:
+        exc = ValueError("bad config")
+        exc.add_note("while loading app.toml")
+        exc.add_note("section [server]")
+        err = exception_to_error(exc)
+        assert err.metadata["notes"] == ["while loading app.toml", "section [server]"]
+
+    def test_notes_on_chained_exceptions(self) -> None:
+        def fail() -> None:
+            try:
+                missing_port()
+            except KeyError as exc:
+                exc.add_note("inner note")
+                raise ValueError("bad config") from exc
+
+        err = Result.attempt(fail).unwrap_err()
+        assert "notes" not in err.metadata
+        assert err.cause is not None
+        assert err.cause.metadata["notes"] == ["inner note"]
@@ -0,0 +1,129 @@
+"""Tests for structured traceback frames (Error.frames) and the traceback depth limit."""
+
+from __future__ import annotations
+
+from collections.abc import Iterator
+from contextlib import contextmanager
+
+from pyropust import (
+    Frame,
+    Ok,
+    Result,
+    catch,
+    exception_to_error,
+    set_traceback_capture,
+    set_traceback_limit,
+    traceback_limit,
+)
+from tests.support import new_error
+
+
+def inner() -> int:
+    raise ValueError("boom")
+
+
+def outer() -> int:
+    return inner()
+
+
+@contextmanager
+def limited(limit: int) -> Iterator[None]:
+    set_traceback_limit(limit)
+    try:
+        yield
+    finally:
+        set_traceback_limit(None)
+
+
+class TestErrorFrames:
+    """Test frames captured from converted exceptions."""
+
+    def test_frames_outermost_first(self) -> None:
+        frames = Result.attempt(outer).unwrap_err().frames
+        assert [frame.function for frame in frames] == ["outer", "inner"]
+        assert all(frame.filename == __file__ for frame in frames)
+        assert frames[-1].line == 'raise ValueError("boom")'
+        assert frames[-1].lineno == inner.__code__.co_firstlineno + 1
+
+    def test_frames_skip_pyropust_internals(self) -> None:
+        frames = catch(outer)().unwrap_err().frames
+        assert [frame.function for frame in frames] == ["outer", "inner"]
+
+    def test_frame_values(self) -> None:
+        first, second = Result.attempt(outer).unwrap_err().frames
+        again = Result.attempt(outer).unwrap_err().frames[0]
+        assert isinstance(first, Frame)
+        assert first == again
+        assert hash(first) == hash(again)
+        assert first != second
+        assert repr(first).startswith("Frame(filename=")
+
+    def test_string_form_is_still_produced(self) -> None:
+        err = Result.attempt(outer).unwrap_err()
+        traceback_text = err.metadata["py_traceback"]
+        assert isinstance(traceback_text, str)
+        for frame in err.frames:
+            assert f"line {frame.lineno}, in {frame.function}" in traceback_text
+
+    def test_chained_exceptions_carry_their_own_frames(self) -> None:
+        def reraise() -> int:
+            try:
+                return outer()
+            except ValueError as exc:
+                raise RuntimeError("wrapped") from exc
+
+        err = Result.attempt(reraise).unwrap_err()
+        assert [frame.function for frame in err.frames] == ["reraise"]
+        assert err.cause is not None
+        assert [frame.function for frame in err.cause.frames] == ["reraise", "outer", "inner"]
+
+    def test_errors_without_exception_have_no_frames(self) -> None:
+        assert new_error(code="c", message="m").frames == []
+        assert exception_to_error(ValueError("never raised")).frames == []
+        wrapped = Ok(1).map_try(lambda _v: outer(), code="c", message="m").unwrap_err()
+        assert wrapped.frames == []
+        assert wrapped.cause is not None
+        assert wrapped.cause.frames[-1].function == "inner"
+
+    def test_context_copies_the_inner_frames(self) -> None:
+        res: Result[int] = Result.attempt(outer)
+        wrapped = res.context("while loading").unwrap_err()
+        assert wrapped.frames == res.unwrap_err().frames
+        assert wrapped.metadata["py_traceback"] == res.unwrap_err().metadata["py_traceback"]
+        assert wrapped.cause is not None
+        assert wrapped.cause.frames == wrapped.frames
+
+    def test_frames_are_not_serialized(self) -> None:
+        err = Result.attempt(outer).unwrap_err()
+        assert "frames" not in err.to_dict()
+        assert type(err).from_dict(err.to_dict()).frames == []
+
+    def test_disabled_capture_has_no_frames(self) -> None:
+        set_traceback_capture(enabled=False)
+        try:
+            err = Result.attempt(outer).unwrap_err()
+        finally:
+            set_traceback_capture(enabled=True)
+        assert err.frames == []
+
+
+class TestTracebackLimit:
+    """Test the process-wide traceback depth limit."""
+
+    def test_unlimited_by_default(self) -> None:
+        assert traceback_limit() is None
+
+    def test_limit_keeps_most_recent_frames(self) -> None:
+        with limited(1):
+            assert traceback_limit() == 1
+            err = Result.attempt(outer).unwrap_err()
+        assert [frame.function for frame in err.frames] == ["inner"]
+        traceback_text = err.metadata["py_traceback"]
+        assert isinstance(traceback_text, str)
+        assert "in inner" in traceback_text
+        assert "in outer" not in traceback_text
+
+    def test_limit_applies_at_conversion(self) -> None:
+        err = Result.attempt(outer).unwrap_err()
+        with limited(1):
+            assert len(err.frames) == 2
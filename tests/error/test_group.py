@@ -0,0 +1,98 @@
+"""Tests for aggregate errors built with Error.group()."""
+
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+from pyropust import Error, ErrorKind
+from tests.support import SampleCode, new_error
+
+
+def field_errors() -> list[Error]:
+    return [
+        new_error(code=SampleCode.VALIDATION, message="name is required", path=["name"]),
+        new_error(code=SampleCode.NOT_FOUND, message="unknown team", path=["team"]),
+        new_error(code=SampleCode.VALIDATION, message="age must be positive", path=["age"]),
+    ]
+
+
+class TestErrorGroup:
+    """Test constructing aggregate errors."""
+
+    def test_group_keeps_children_in_order(self) -> None:
+        children = field_errors()
+        err = Error.group(SampleCode.VALIDATION, "form is invalid", children)
+        assert err.code == "validation"
+        assert err.message == "form is invalid"
+        assert err.kind == ErrorKind.InvalidInput
+        assert err.children == children
+        assert err.cause is None
+
+    def test_group_accepts_any_iterable_and_options(self) -> None:
+        err = Error.group(
+            SampleCode.CUSTOM,
+            "import failed",
+            iter(field_errors()),
+            kind=ErrorKind.Conflict,
+            op="import",
+            path=["rows"],
+            metadata={"batch": 7},
+        )
+        assert len(err.children) == 3
+        assert err.kind == ErrorKind.Conflict
+        assert err.op == "import"
+        assert err.path == ["rows"]
+        assert err.metadata == {"batch": 7}
+
+    def test_group_rejects_invalid_errors(self) -> None:
+        with pytest.raises(TypeError, match="group expects Error values"):
+            Error.group(SampleCode.ERROR, "boom", ["not an error"])  # type: ignore[list-item]
+        with pytest.raises(ValueError, match="at least one error"):
+            Error.group(SampleCode.ERROR, "boom", [])
+
+    def test_groups_nest(self) -> None:
+        inner = Error.group(SampleCode.VALIDATION, "row 1", field_errors())
+        outer = Error.group(SampleCode.CUSTOM, "import failed", [inner])
+        assert outer.children[0].children == inner.children
+
+
+class TestErrorGroupRepr:
+    """Test the per-code summary in repr()."""
+
+    def test_repr_counts_children_per_code(self) -> None:
+        err = Error.group(SampleCode.VALIDATION, "form is invalid", field_errors())
+        assert repr(err) == (
+            "Error(kind=ErrorKind.InvalidInput, code='validation', message='form is invalid', "
+            "children={'validation': 2, '404': 1})"
+        )
+
+    def test_repr_without_children_is_unchanged(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom")
+        assert repr(err) == "Error(kind=ErrorKind.InvalidInput, code='error', message='boom')"
+
+
+class TestErrorGroupSerialization:
+    """Test children through the dict, JSON and pickle formats."""
+
+    def test_to_dict_lists_children(self) -> None:
+        err = Error.group(SampleCode.VALIDATION, "form is invalid", field_errors())
+        children = err.to_dict()["children"]
+        assert isinstance(children, list)
+        assert [child["path"] for child in children] == [["name"], ["team"], ["age"]]
+
+    def test_round_trips(self) -> None:
+        inner = Error.group(SampleCode.VALIDATION, "row 1", field_errors())
+        err = Error.group(SampleCode.CUSTOM, "import failed", [inner], metadata={"rows": 1})
+        assert Error.from_dict(err.to_dict()) == err
+        assert Error.from_json(err.to_json()) == err
+        assert pickle.loads(pickle.dumps(err)) == err
+
+    def test_children_participate_in_equality_and_hash(self) -> None:
+        a = Error.group(SampleCode.VALIDATION, "form is invalid", field_errors())
+        b = Error.group(SampleCode.VALIDATION, "form is invalid", field_errors())
+        c = Error.group(SampleCode.VALIDATION, "form is invalid", field_errors()[:2])
+        assert a == b
+        assert hash(a) == hash(b)
+        assert a != c
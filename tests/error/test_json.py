@@ -0,0 +1,108 @@
+"""Tests for Error JSON serialization (to_json, from_json)."""
+
+from __future__ import annotations
+
+import json
+
+import pytest
+
+from pyropust import Error, ErrorKind
+from tests.support import SampleCode, new_error, wrap_error
+
+
+def sample_error() -> Error:
+    inner = new_error(
+        code=SampleCode.ERROR,
+        message="inner",
+        kind=ErrorKind.NotFound,
+        op="load",
+        path=["users", 0],
+        expected="int",
+        got="str",
+        metadata={"n": 1, "tags": ["a"]},
+    )
+    return wrap_error(inner, code=SampleCode.CUSTOM, message="outer", metadata={"k": "v"})
+
+
+class TestErrorToJson:
+    """Test Error.to_json."""
+
+    def test_document_matches_dict_format(self) -> None:
+        err = sample_error()
+        document = json.loads(err.to_json())
+        assert document.pop("schema_version") == 1
+        assert document == err.to_dict()
+
+    def test_nested_cause_has_no_schema_version(self) -> None:
+        document = json.loads(sample_error().to_json())
+        assert "schema_version" not in document["cause"]
+        assert document["cause"]["code"] == SampleCode.ERROR.value
+
+    def test_strict_stringifies_metadata(self) -> None:
+        document = json.loads(sample_error().to_json(strict=True))
+        assert document["cause"]["metadata"] == {"n": "1", "tags": '["a"]'}
+
+
+class TestErrorFromJson:
+    """Test Error.from_json round-trips and validation."""
+
+    def test_round_trip_str_and_bytes(self) -> None:
+        err = sample_error()
+        assert Error.from_json(err.to_json()) == err
+        assert Error.from_json(err.to_json().encode()) == err
+
+    def test_optional_fields_may_be_omitted(self) -> None:
+        document = {"schema_version": 1, "kind": "Internal", "code": "c", "message": "m"}
+        err = Error.from_json(json.dumps(document))
+        assert err == new_error(code="c", message="m", kind=ErrorKind.Internal)
+
+    def test_rejects_invalid_documents(self) -> None:
+        valid = json.loads(sample_error().to_json())
+        cases: list[tuple[str, str]] = [
+            ("{", "invalid error JSON"),
+            ("[]", "expected a JSON object"),
+            ('{"kind": "Internal", "code": "c", "message": "m"}', "missing 'schema_version'"),
+            (json.dumps({**valid, "schema_version": 2}), "unsupported schema_version 2"),
+            (json.dumps({**valid, "extra": 1}), "unknown field 'extra'"),
+            (json.dumps({**valid, "code": 1}), "'code' must be a string"),
+            (json.dumps({**valid, "kind": "Nope"}), "not a known ErrorKind"),
+            (json.dumps({**valid, "path": [-1]}), r"'path\[0\]'"),
+            (json.dumps({**valid, "cause": "legacy"}), "'cause' must be an object or null"),
+            (json.dumps({**valid, "cause": {**valid["cause"], "op": 3}}), "'cause.op'"),
+        ]
+        for document, message in cases:
+            with pytest.raises(ValueError, match=message):
+                Error.from_json(document)
+
+    def test_rejects_non_text_input(self) -> None:
+        with pytest.raises(TypeError, match="str or bytes"):
+            Error.from_json(123)  # type: ignore[arg-type]
+
+    def test_strict_rejects_non_string_metadata(self) -> None:
+        err = sample_error()
+        with pytest.raises(ValueError, match="'cause.metadata.n' must be a string"):
+            Error.from_json(err.to_json(), strict=True)
+        assert Error.from_json(err.to_json(strict=True), strict=True).metadata == {"k": "v"}
+
+
+def chain_of(length: int) -> Error:
+    error = new_error(code=SampleCode.NOT_FOUND, message="row missing")
+    for index in range(length - 1):
+        error = wrap_error(error, code=SampleCode.ERROR, message=f"layer {index}")
+    return error
+
+
+class TestErrorJsonDepth:
+    """Test that every document to_json writes can be read back."""
+
+    # The innermost error's `path` / `metadata` / `children` sit one level below it.
+    LONGEST_CHAIN = 126
+
+    def test_round_trip_at_the_limit(self) -> None:
+        error = chain_of(self.LONGEST_CHAIN)
+        assert Error.from_json(error.to_json()) == error
+
+    def test_rejects_deeper_chains(self) -> None:
+        for length in (self.LONGEST_CHAIN + 1, 5_000):
+            with pytest.raises(ValueError, match="nested more than 127 levels"):
+                chain_of(length).to_json()
@@ -0,0 +1,98 @@
+"""Tests for the ErrorKind taxonomy (built-ins, value, from_str, register, all)."""
+
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+from pyropust import Error, ErrorKind
+from tests.support import SampleCode, new_error
+
+BUILTIN_NAMES = [
+    "InvalidInput",
+    "NotFound",
+    "Internal",
+    "PermissionDenied",
+    "Conflict",
+    "Timeout",
+    "Unavailable",
+    "Cancelled",
+    "RateLimited",
+    "Unauthenticated",
+    "AlreadyExists",
+]
+
+
+class TestBuiltinKinds:
+    """Test the built-in kinds and their accessors."""
+
+    def test_builtin_attributes(self) -> None:
+        for name in BUILTIN_NAMES:
+            kind = getattr(ErrorKind, name)
+            assert kind.value == name
+            assert repr(kind) == f"ErrorKind.{name}"
+
+    def test_from_str(self) -> None:
+        assert ErrorKind.from_str("Timeout") == ErrorKind.Timeout
+
+    def test_from_str_unknown_raises(self) -> None:
+        with pytest.raises(ValueError, match="unknown ErrorKind 'Nope'"):
+            ErrorKind.from_str("Nope")
+
+    def test_all_starts_with_builtins(self) -> None:
+        names = [kind.value for kind in ErrorKind.all()]
+        assert names[: len(BUILTIN_NAMES)] == BUILTIN_NAMES
+
+    def test_iteration_goes_through_all(self) -> None:
+        # Extension types cannot take a metaclass, so the class itself is not iterable.
+        with pytest.raises(TypeError):
+            iter(ErrorKind)  # type: ignore[call-overload]
+        snapshot = ErrorKind.all()
+        ErrorKind.register("Snapshot")
+        assert ErrorKind.from_str("Snapshot") not in snapshot
+        assert ErrorKind.from_str("Snapshot") in ErrorKind.all()
+
+    def test_error_accepts_new_builtin_by_name(self) -> None:
+        error = new_error(code=SampleCode.ERROR, message="slow", kind="RateLimited")
+        assert error.kind == ErrorKind.RateLimited
+
+
+class TestCustomKinds:
+    """Test runtime registration of application kinds."""
+
+    def test_register_returns_usable_kind(self) -> None:
+        billing = ErrorKind.register("Billing")
+        assert billing.value == "Billing"
+        assert ErrorKind.from_str("Billing") == billing
+        assert billing in ErrorKind.all()
+
+    def test_register_is_idempotent(self) -> None:
+        assert ErrorKind.register("Quota") == ErrorKind.register("Quota")
+        assert [k.value for k in ErrorKind.all()].count("Quota") == 1
+
+    def test_register_builtin_name_returns_builtin(self) -> None:
+        assert ErrorKind.register("NotFound") == ErrorKind.NotFound
+
+    def test_register_rejects_invalid_names(self) -> None:
+        for name in ["", "1st", "has space", "dash-ed"]:
+            with pytest.raises(ValueError, match="invalid ErrorKind name"):
+                ErrorKind.register(name)
+
+    def test_custom_kind_in_errors(self) -> None:
+        ErrorKind.register("Fraud")
+        error = new_error(code=SampleCode.ERROR, message="blocked", kind="Fraud")
+        assert error.kind.value == "Fraud"
+        assert Error.from_dict(error.to_dict()) == error
+
+    def test_unregistered_name_rejected(self) -> None:
+        with pytest.raises(TypeError, match="unknown ErrorKind 'NeverRegistered'"):
+            new_error(code=SampleCode.ERROR, message="m", kind="NeverRegistered")
+
+    def test_non_string_kind_rejected(self) -> None:
+        with pytest.raises(TypeError, match="invalid 'kind' field"):
+            new_error(code=SampleCode.ERROR, message="m", kind=1)  # type: ignore[arg-type]
+
+    def test_custom_kind_pickles(self) -> None:
+        kind = ErrorKind.register("Shipping")
+        assert pickle.loads(pickle.dumps(kind)) == kind
@@ -0,0 +1,156 @@
+"""Tests for typed (JSON-compatible) Error metadata."""
+
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+from pyropust import Err, Error, Ok, Result
+from tests.support import SampleCode, new_error, wrap_error
+
+TYPED: dict[str, object] = {
+    "text": "value",
+    "retry_after": 30,
+    "ratio": 0.5,
+    "burst": True,
+    "missing": None,
+    "tags": ["a", 1],
+    "nested": {"limit": 10, "ids": [1, 2]},
+}
+
+
+def nested_list(depth: int) -> list[object]:
+    nested: list[object] = []
+    for _ in range(depth - 1):
+        nested = [nested]
+    return nested
+
+
+class TestTypedMetadata:
+    """Test that metadata values keep their JSON type."""
+
+    def test_values_keep_their_type(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom", metadata=TYPED)
+        assert err.metadata == TYPED
+        assert err.metadata["retry_after"] == 30
+        assert err.metadata["burst"] is True
+
+    def test_tuples_become_lists(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom", metadata={"pair": (1, 2)})
+        assert err.metadata["pair"] == [1, 2]
+
+    def test_large_unsigned_int_is_kept(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom", metadata={"big": 2**64 - 1})
+        assert err.metadata["big"] == 2**64 - 1
+
+    def test_typed_metadata_on_wrap_and_context(self) -> None:
+        inner = new_error(code=SampleCode.ERROR, message="inner")
+        wrapped = wrap_error(inner, code=SampleCode.CUSTOM, message="outer", metadata={"n": 1})
+        assert wrapped.metadata["n"] == 1
+
+        res: Result[int] = Err(inner).context("ctx", metadata={"attempt": 2})
+        assert res.unwrap_err().metadata["attempt"] == 2
+
+    def test_rejects_non_json_values(self) -> None:
+        with pytest.raises(TypeError, match="JSON-compatible"):
+            new_error(code=SampleCode.ERROR, message="boom", metadata={"obj": object()})
+        with pytest.raises(TypeError, match="keys must be str"):
+            new_error(code=SampleCode.ERROR, message="boom", metadata={"d": {1: "x"}})
+        with pytest.raises(ValueError, match="finite"):
+            new_error(code=SampleCode.ERROR, message="boom", metadata={"f": float("nan")})
+        with pytest.raises(ValueError, match="64 bits"):
+            new_error(code=SampleCode.ERROR, message="boom", metadata={"i": 2**70})
+
+    def test_rejects_circular_values(self) -> None:
+        items: list[object] = []
+        items.append(items)
+        mapping: dict[str, object] = {}
+        mapping["self"] = mapping
+        with pytest.raises(ValueError, match="Circular reference detected"):
+            new_error(code=SampleCode.ERROR, message="boom", metadata={"x": items})
+        with pytest.raises(ValueError, match="Circular reference detected"):
+            new_error(code=SampleCode.ERROR, message="boom", metadata=mapping)
+
+    def test_shared_values_are_not_circular(self) -> None:
+        shared = [1, 2]
+        error = new_error(code=SampleCode.ERROR, message="boom", metadata={"x": [shared, shared]})
+        assert error.metadata["x"] == [[1, 2], [1, 2]]
+
+    def test_rejects_deeply_nested_values(self) -> None:
+        nested: list[object] = []
+        for _ in range(100_000):
+            nested = [nested]
+        with pytest.raises(ValueError, match="nested more than 125 levels"):
+            new_error(code=SampleCode.ERROR, message="boom", metadata={"x": nested})
+
+    def test_nesting_limit_leaves_room_for_the_document(self) -> None:
+        # The error object and its metadata map take two of JSON's 127 levels.
+        error = new_error(code=SampleCode.ERROR, message="boom", metadata={"x": nested_list(125)})
+        assert Error.from_json(error.to_json()) == error
+        with pytest.raises(ValueError, match="nested more than 125 levels"):
+            new_error(code=SampleCode.ERROR, message="boom", metadata={"x": nested_list(126)})
+        # Wrapped in a Result envelope the same error is one level too deep.
+        with pytest.raises(ValueError, match="nested more than 127 levels"):
+            Err(error).to_json()
+
+    def test_encoder_output_is_checked_for_cycles(self) -> None:
+        items: list[object] = []
+        items.append(items)
+        res: Result[int] = Ok(1)
+        with pytest.raises(ValueError, match="Circular reference detected"):
+            res.to_json(encoder=lambda _v: items)
+
+    def test_equality_and_hash_with_typed_values(self) -> None:
+        a = new_error(code=SampleCode.ERROR, message="boom", metadata=TYPED)
+        b = new_error(code=SampleCode.ERROR, message="boom", metadata=dict(reversed(TYPED.items())))
+        assert a == b
+        assert hash(a) == hash(b)
+        assert a != new_error(code=SampleCode.ERROR, message="boom", metadata={"retry_after": "30"})
+
+
+class TestMetadataRoundTrip:
+    """Test typed metadata through to_dict / from_dict and pickle."""
+
+    def test_dict_round_trip(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom", metadata=TYPED)
+        data = err.to_dict()
+        assert data["metadata"] == TYPED
+        assert Error.from_dict(data) == err
+
+    def test_pickle_round_trip(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom", metadata=TYPED)
+        assert pickle.loads(pickle.dumps(err)) == err
+
+
+class TestStrictMetadata:
+    """Test the string-only strict mode of to_dict / from_dict."""
+
+    def test_to_dict_strict_stringifies_values(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom", metadata=TYPED)
+        metadata = err.to_dict(strict=True)["metadata"]
+        assert metadata == {
+            "text": "value",
+            "retry_after": "30",
+            "ratio": "0.5",
+            "burst": "true",
+            "missing": "null",
+            "tags": '["a",1]',
+            "nested": '{"ids":[1,2],"limit":10}',
+        }
+
+    def test_to_dict_strict_applies_to_causes(self) -> None:
+        inner = new_error(code=SampleCode.ERROR, message="inner", metadata={"n": 1})
+        outer = wrap_error(inner, code=SampleCode.CUSTOM, message="outer")
+        cause = outer.to_dict(strict=True)["cause"]
+        assert isinstance(cause, dict)
+        assert cause["metadata"] == {"n": "1"}
+
+    def test_from_dict_strict_accepts_strings(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom", metadata={"k": "v"})
+        assert Error.from_dict(err.to_dict(), strict=True) == err
+
+    def test_from_dict_strict_rejects_non_strings(self) -> None:
+        err = new_error(code=SampleCode.ERROR, message="boom", metadata={"n": 1})
+        with pytest.raises(TypeError, match="metadata value for 'n' must be str"):
+            Error.from_dict(err.to_dict(), strict=True)
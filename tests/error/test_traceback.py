@@ -0,0 +1,118 @@
+"""Tests for lazy traceback capture and the process-wide capture switch."""
+
+from __future__ import annotations
+
+import pickle
+import traceback
+from collections.abc import Callable, Iterator
+from contextlib import contextmanager
+from typing import Any
+
+from pyropust import (
+    Ok,
+    Result,
+    catch,
+    set_traceback_capture,
+    traceback_capture_enabled,
+)
+
+
+def fail() -> int:
+    raise ValueError("boom")
+
+
+@contextmanager
+def count_formatting() -> Iterator[list[int]]:
+    calls: list[int] = []
+    original: Callable[..., list[str]] = traceback.format_exception
+
+    def spy(*args: Any, **kwargs: Any) -> list[str]:
+        calls.append(1)
+        return original(*args, **kwargs)
+
+    traceback.format_exception = spy
+    try:
+        yield calls
+    finally:
+        traceback.format_exception = original
+
+
+@contextmanager
+def capture_disabled() -> Iterator[None]:
+    set_traceback_capture(enabled=False)
+    try:
+        yield
+    finally:
+        set_traceback_capture(enabled=True)
+
+
+class TestLazyTraceback:
+    """Test that tracebacks are formatted on first use only."""
+
+    def test_conversion_does_not_format(self) -> None:
+        with count_formatting() as calls:
+            Result.attempt(fail).unwrap_err()
+            catch(fail)().unwrap_err()
+            Ok(1).map_try(lambda _v: fail(), code="c", message="m").unwrap_err()
+        assert calls == []
+
+    def test_comparing_and_hashing_do_not_format(self) -> None:
+        with count_formatting() as calls:
+            first = Result.attempt(fail).unwrap_err()
+            second = Result.attempt(fail).unwrap_err()
+            assert first == second
+            assert hash(first) == hash(second)
+        assert calls == []
+
+    def test_formatted_once_on_first_read(self) -> None:
+        with count_formatting() as calls:
+            err = Result.attempt(fail).unwrap_err()
+            first = err.metadata["py_traceback"]
+            assert err.metadata["py_traceback"] == first
+            assert err.to_dict()["metadata"] == err.metadata
+        assert len(calls) == 1
+        assert isinstance(first, str)
+        assert first.startswith("Traceback (most recent call last):")
+        assert first.endswith("ValueError: boom\n")
+        assert "in fail" in first
+
+    def test_cause_traceback_is_shared(self) -> None:
+        with count_formatting() as calls:
+            err = Ok(1).map_try(lambda _v: fail(), code="c", message="m").unwrap_err()
+            assert err.cause is not None
+            assert err.metadata["cause_py_traceback"] == err.cause.metadata["py_traceback"]
+        assert len(calls) == 1
+
+    def test_serialization_includes_traceback(self) -> None:
+        err = Result.attempt(fail).unwrap_err()
+        assert "py_traceback" in err.to_json()
+        restored = pickle.loads(pickle.dumps(err))
+        assert restored == err
+        assert restored.metadata["py_traceback"] == err.metadata["py_traceback"]
+
+    def test_context_keeps_pending_traceback(self) -> None:
+        res: Result[int] = Result.attempt(fail)
+        err = res.context("while loading").unwrap_err()
+        assert err.metadata["py_traceback"] == res.unwrap_err().metadata["py_traceback"]
+
+
+class TestTracebackCaptureSwitch:
+    """Test disabling traceback capture process-wide."""
+
+    def test_enabled_by_default(self) -> None:
+        assert traceback_capture_enabled() is True
+
+    def test_disabled_capture_omits_traceback(self) -> None:
+        with capture_disabled():
+            assert traceback_capture_enabled() is False
+            err = Result.attempt(fail).unwrap_err()
+            wrapped = Ok(1).map_try(lambda _v: fail(), code="c", message="m").unwrap_err()
+        assert err.metadata == {"exception": "ValueError"}
+        assert "cause_py_traceback" not in wrapped.metadata
+        assert wrapped.metadata["cause_exception"] == "ValueError"
+
+    def test_switch_only_affects_later_conversions(self) -> None:
+        err = Result.attempt(fail).unwrap_err()
+        with capture_disabled():
+            assert "py_traceback" in err.metadata
+        assert "py_traceback" in Result.attempt(fail).unwrap_err().metadata
@@ -0,0 +1,46 @@
+"""Tests for Option.collect()."""
+
+from __future__ import annotations
+
+from collections.abc import Iterator
+
+import pytest
+
+from pyropust import None_, Option, Some
+
+
+class TestOptionCollect:
+    """Test collecting an iterable of Options into an Option of a container."""
+
+    def test_all_some_collects_into_list(self) -> None:
+        options: list[Option[int]] = [Some(1), Some(2), Some(3)]
+        assert Option.collect(options) == Some([1, 2, 3])
+
+    def test_empty_iterable_is_some(self) -> None:
+        options: list[Option[int]] = []
+        assert Option.collect(options) == Some([])
+
+    def test_none_short_circuits(self) -> None:
+        consumed: list[int] = []
+
+        def options() -> Iterator[Option[int]]:
+            for i in range(5):
+                consumed.append(i)
+                yield None_() if i == 2 else Some(i)
+
+        assert Option.collect(options()).is_none()
+        assert consumed == [0, 1, 2]
+
+    def test_into_tuple_and_dict(self) -> None:
+        values: list[Option[int]] = [Some(1), Some(2)]
+        pairs: list[Option[tuple[str, int]]] = [Some(("a", 1)), Some(("b", 2))]
+        assert Option.collect(values, into=tuple) == Some((1, 2))
+        assert Option.collect(pairs, into=dict) == Some({"a": 1, "b": 2})
+
+    def test_into_any_constructor(self) -> None:
+        options: list[Option[str]] = [Some("b"), Some("a")]
+        assert Option.collect(options, into=sorted) == Some(["a", "b"])
+
+    def test_rejects_non_option_items(self) -> None:
+        with pytest.raises(TypeError, match="collect expects Option values"):
+            Option.collect([Some(1), 1])  # type: ignore[list-item]
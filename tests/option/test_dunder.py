@@ -0,0 +1,63 @@
+"""Tests for Option value semantics (__eq__, __hash__, __repr__, __bool__)."""
+
+from __future__ import annotations
+
+import pytest
+
+from pyropust import None_, Ok, Option, Some
+
+
+class TestOptionEquality:
+    """Test Option equality delegating to the payload."""
+
+    def test_some_equal_when_values_equal(self) -> None:
+        assert Some(1) == Some(1)
+        assert Some((1, "a")) == Some((1, "a"))
+
+    def test_some_not_equal_when_values_differ(self) -> None:
+        assert Some(1) != Some(2)
+
+    def test_none_equal_to_none(self) -> None:
+        assert None_() == None_()
+
+    def test_some_not_equal_to_none(self) -> None:
+        opt: Option[int] = None_()
+        assert Some(1) != opt
+
+    def test_not_equal_to_other_types(self) -> None:
+        assert Some(1) != 1
+        assert Some(1) != Ok(1)
+        assert None_() != None
+
+
+class TestOptionHash:
+    """Test Option hashing."""
+
+    def test_equal_options_hash_equal(self) -> None:
+        assert hash(Some("a")) == hash(Some("a"))
+        assert hash(None_()) == hash(None_())
+
+    def test_usable_as_dict_keys(self) -> None:
+        counts = {Some(1): 1, None_(): 2}
+        assert counts[Some(1)] == 1
+        assert counts[None_()] == 2
+
+
+class TestOptionRepr:
+    """Test Option repr."""
+
+    def test_some_repr(self) -> None:
+        assert repr(Some(3)) == "Some(3)"
+
+    def test_none_repr(self) -> None:
+        assert repr(None_()) == "None_"
+
+
+class TestOptionBool:
+    """Test that Option refuses implicit truthiness."""
+
+    def test_bool_raises(self) -> None:
+        with pytest.raises(TypeError, match="is_some"):
+            bool(Some(1))
+        with pytest.raises(TypeError, match="is_some"):
+            bool(None_())
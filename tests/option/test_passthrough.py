@@ -0,0 +1,59 @@
+"""Tests that Option combinators which do not apply return the receiver itself."""
+
+from __future__ import annotations
+
+import copy
+import pickle
+
+from pyropust import Err, Error, None_, Option, Result, Some
+
+
+class TestNoneSingleton:
+    """Test that every None_ is the same object."""
+
+    def test_constructor_returns_same_instance(self) -> None:
+        assert None_() is None_()
+
+    def test_derived_nones_are_the_singleton(self) -> None:
+        none: Option[int] = None_()
+        res: Result[int] = Err(Error.new("c", "m"))
+        assert Some(1).filter(lambda x: x > 1) is none
+        assert res.ok() is none
+        assert Some(1).xor(Some(2)) is none
+
+    def test_copy_and_pickle_keep_the_singleton(self) -> None:
+        none: Option[int] = None_()
+        assert copy.copy(none) is none
+        assert copy.deepcopy(none) is none
+        assert pickle.loads(pickle.dumps(none)) is none
+
+
+class TestSomePassthrough:
+    """Test that combinators return a Some unchanged when nothing changes."""
+
+    def test_inspect_and_filter_return_same_some(self) -> None:
+        opt = Some(1)
+        assert opt.inspect(id) is opt
+        assert opt.filter(lambda x: x == 1) is opt
+        assert opt.or_else(lambda: Some(2)) is opt
+        assert copy.copy(opt) is opt
+
+    def test_returned_options_pass_through(self) -> None:
+        inner = Some(2)
+        assert Some(1).and_then(lambda _: inner) is inner
+        assert Some(1).and_(inner) is inner
+        assert Some(inner).flatten() is inner
+
+
+class TestNonePassthrough:
+    """Test that Some-side combinators return None_ unchanged."""
+
+    def test_map_and_and_then_return_none(self) -> None:
+        none: Option[int] = None_()
+        assert none.map(lambda x: x + 1) is none
+        assert none.and_then(lambda x: Some(x + 1)) is none
+        assert none.flatten() is none
+
+    def test_map_try_wraps_same_none(self) -> None:
+        none: Option[int] = None_()
+        assert none.map_try(int, code="parse", message="not an int").unwrap() is none
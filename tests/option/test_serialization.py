@@ -0,0 +1,66 @@
+"""Tests for Option wire formats (to_dict, from_dict, to_json, from_json)."""
+
+from __future__ import annotations
+
+import datetime as dt
+import json
+
+import pytest
+
+from pyropust import None_, Option, Some
+
+
+class TestOptionDict:
+    """Test the {"some": ...} / {"none": None} dict envelope."""
+
+    def test_round_trip(self) -> None:
+        some: Option[int] = Some(3)
+        none: Option[int] = None_()
+        assert some.to_dict() == {"some": 3}
+        assert none.to_dict() == {"none": None}
+        assert Option.from_dict({"some": 3}) == some
+        assert Option.from_dict({"none": None}) == none
+
+    def test_encoder_and_decoder_hooks(self) -> None:
+        opt: Option[dt.date] = Some(dt.date(2024, 1, 2))
+        data = opt.to_dict(encoder=lambda d: d.isoformat())
+        assert data == {"some": "2024-01-02"}
+        assert Option.from_dict(data, decoder=dt.date.fromisoformat) == opt
+
+    def test_rejects_malformed_envelopes(self) -> None:
+        for data in [{}, {"some": 1, "none": None}, {"value": 1}]:
+            with pytest.raises(ValueError, match="exactly one of 'some' or 'none'"):
+                Option.from_dict(data)
+        with pytest.raises(ValueError, match="'none' must be None"):
+            Option.from_dict({"none": 1})
+
+
+class TestOptionJson:
+    """Test the versioned JSON envelope."""
+
+    def test_round_trip(self) -> None:
+        some: Option[list[int]] = Some([1, 2])
+        none: Option[int] = None_()
+        assert json.loads(some.to_json()) == {"schema_version": 1, "some": [1, 2]}
+        assert json.loads(none.to_json()) == {"schema_version": 1, "none": None}
+        assert Option.from_json(some.to_json()) == some
+        assert Option.from_json(none.to_json().encode()) == none
+
+    def test_some_none_payload_is_not_none(self) -> None:
+        opt: Option[None] = Some(None)
+        assert Option.from_json(opt.to_json()) == opt
+
+    def test_encoder_and_decoder_hooks(self) -> None:
+        opt: Option[dt.date] = Some(dt.date(2024, 1, 2))
+        document = opt.to_json(encoder=lambda d: d.isoformat())
+        assert Option.from_json(document, decoder=dt.date.fromisoformat) == opt
+
+    def test_rejects_invalid_documents(self) -> None:
+        cases: list[tuple[str, str]] = [
+            ('{"some": 1}', "missing 'schema_version'"),
+            ('{"schema_version": 1, "some": 1, "none": null}', "exactly one of 'some' or 'none'"),
+            ('{"schema_version": 1, "none": 0}', "'none' must be null"),
+        ]
+        for document, message in cases:
+            with pytest.raises(ValueError, match=message):
+                Option.from_json(document)
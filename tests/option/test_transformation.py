@@ -36,7 +36,7 @@ class TestOptionMapTry:
         assert err.code == SampleCode.BAD_INPUT
         assert err.message == "invalid int"
         assert err.cause is not None
-        assert "py_exception" in err.cause
+        assert err.cause.code == "py_exception"
 
     def test_map_try_skips_on_none(self) -> None:
         res = None_().map_try(int, code=SampleCode.ERROR, message="invalid int")
@@ -137,7 +137,7 @@ class TestOptionAndThenTry:
         assert err.code == SampleCode.BAD_INPUT
         assert err.message == "invalid int"
         assert err.cause is not None
-        assert "py_exception" in err.cause
+        assert err.cause.code == "py_exception"
 
     def test_and_then_try_skips_on_none(self) -> None:
         res = None_().and_then_try(
@@ -0,0 +1,61 @@
+"""Tests for Result.collect()."""
+
+from __future__ import annotations
+
+from collections.abc import Iterator
+
+import pytest
+
+from pyropust import Ok, Result
+from tests.support import err_msg
+
+
+class TestResultCollect:
+    """Test collecting an iterable of Results into a Result of a container."""
+
+    def test_all_ok_collects_into_list(self) -> None:
+        results: list[Result[int]] = [Ok(1), Ok(2), Ok(3)]
+        assert Result.collect(results) == Ok([1, 2, 3])
+
+    def test_empty_iterable_is_ok(self) -> None:
+        results: list[Result[int]] = []
+        assert Result.collect(results) == Ok([])
+
+    def test_returns_first_err(self) -> None:
+        results: list[Result[int]] = [Ok(1), err_msg("first"), err_msg("second")]
+        assert Result.collect(results).unwrap_err().message == "first"
+
+    def test_stops_consuming_at_first_err(self) -> None:
+        consumed: list[int] = []
+
+        def results() -> Iterator[Result[int]]:
+            for i in range(5):
+                consumed.append(i)
+                yield err_msg("boom") if i == 1 else Ok(i)
+
+        assert Result.collect(results()).is_err()
+        assert consumed == [0, 1]
+
+    def test_into_tuple(self) -> None:
+        results: list[Result[int]] = [Ok(1), Ok(2)]
+        assert Result.collect(results, into=tuple) == Ok((1, 2))
+
+    def test_into_dict_from_pairs(self) -> None:
+        results: list[Result[tuple[str, int]]] = [Ok(("a", 1)), Ok(("b", 2))]
+        assert Result.collect(results, into=dict) == Ok({"a": 1, "b": 2})
+
+    def test_into_any_constructor(self) -> None:
+        results: list[Result[int]] = [Ok(2), Ok(1), Ok(2)]
+        assert Result.collect(results, into=set) == Ok({1, 2})
+        assert Result.collect(results, into=list) == Ok([2, 1, 2])
+
+    def test_into_is_not_called_on_err(self) -> None:
+        def fail(_values: list[int]) -> int:
+            pytest.fail("into called on Err")
+
+        results: list[Result[int]] = [err_msg("boom")]
+        assert Result.collect(results, into=fail).is_err()
+
+    def test_rejects_non_result_items(self) -> None:
+        with pytest.raises(TypeError, match="collect expects Result values"):
+            Result.collect([Ok(1), 2])  # type: ignore[list-item]
@@ -0,0 +1,82 @@
+"""Tests for combining independent Results (zip, zip_with, all, map_n)."""
+
+from __future__ import annotations
+
+import pytest
+
+from pyropust import Err, Ok, Result
+from tests.support import SampleCode, new_error
+
+
+def invalid(field: str) -> Result[int]:
+    return Err(new_error(code=SampleCode.VALIDATION, message=f"{field} is invalid", path=[field]))
+
+
+class TestResultZip:
+    """Test zip / zip_with on pairs of Results."""
+
+    def test_zip_pairs_ok_values(self) -> None:
+        left: Result[int] = Ok(1)
+        right: Result[str] = Ok("a")
+        assert left.zip(right) == Ok((1, "a"))
+
+    def test_zip_with_combines_ok_values(self) -> None:
+        left: Result[int] = Ok(2)
+        right: Result[int] = Ok(3)
+        assert left.zip_with(right, lambda a, b: a * b) == Ok(6)
+
+    def test_zip_keeps_a_single_failure_as_child(self) -> None:
+        ok: Result[int] = Ok(1)
+        err = ok.zip(invalid("age")).unwrap_err()
+        assert err.code == "zip"
+        assert err.message == "1 of 2 items failed"
+        assert [child.path for child in err.children] == [[1, "age"]]
+
+    def test_zip_accumulates_both_failures(self) -> None:
+        err = invalid("name").zip(invalid("age")).unwrap_err()
+        assert [child.message for child in err.children] == ["name is invalid", "age is invalid"]
+        assert [child.path for child in err.children] == [[0, "name"], [1, "age"]]
+
+    def test_zip_with_does_not_call_f_on_failure(self) -> None:
+        def combine(_a: int, _b: int) -> int:
+            pytest.fail("f called on Err")
+
+        assert invalid("name").zip_with(Ok(1), combine).is_err()
+
+
+class TestResultAll:
+    """Test the n-ary Result.all and Result.map_n."""
+
+    def test_all_collects_a_tuple(self) -> None:
+        assert Result.all(Ok(1), Ok("a"), Ok(None)) == Ok((1, "a", None))
+        assert Result.all() == Ok(())
+
+    def test_all_accumulates_every_failure(self) -> None:
+        err = Result.all(invalid("name"), Ok(1), invalid("age")).unwrap_err()
+        assert err.code == "all"
+        assert err.message == "2 of 3 items failed"
+        assert [child.path for child in err.children] == [[0, "name"], [2, "age"]]
+
+    def test_map_n_applies_f_to_ok_values(self) -> None:
+        assert Result.map_n(lambda a, b, c: a + b + c, Ok(1), Ok(2), Ok(3)) == Ok(6)
+
+    def test_map_n_builds_records_from_independent_validations(self) -> None:
+        def user(name: str, age: int) -> dict[str, object]:
+            return {"name": name, "age": age}
+
+        name: Result[str] = Ok("alice")
+        assert Result.map_n(user, name, Ok(30)) == Ok({"name": "alice", "age": 30})
+        err = Result.map_n(user, invalid("name"), invalid("age")).unwrap_err()
+        assert len(err.children) == 2
+
+    def test_map_n_does_not_call_f_on_failure(self) -> None:
+        def build(*_values: int) -> int:
+            pytest.fail("f called on Err")
+
+        assert Result.map_n(build, Ok(1), invalid("age")).is_err()
+
+    def test_rejects_non_result_arguments(self) -> None:
+        with pytest.raises(TypeError, match="all expects Result values"):
+            Result.all(Ok(1), 2)  # type: ignore[call-overload]
+        with pytest.raises(TypeError, match="map_n expects Result values"):
+            Result.map_n(str, 2)  # type: ignore[call-overload]
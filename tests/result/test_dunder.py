@@ -0,0 +1,67 @@
+"""Tests for Result value semantics (__eq__, __hash__, __repr__, __bool__)."""
+
+from __future__ import annotations
+
+import pytest
+
+from pyropust import Err, Error, Ok, Result, Some
+from tests.support import err_msg
+
+
+class TestResultEquality:
+    """Test Result equality delegating to the payload."""
+
+    def test_ok_equal_when_values_equal(self) -> None:
+        assert Ok(1) == Ok(1)
+        assert Ok([1, 2]) == Ok([1, 2])
+
+    def test_ok_not_equal_when_values_differ(self) -> None:
+        assert Ok(1) != Ok(2)
+
+    def test_ok_not_equal_to_err(self) -> None:
+        res: Result[int] = err_msg("boom")
+        assert Ok(1) != res
+
+    def test_err_compares_error_payload(self) -> None:
+        error = Error.new(code="e", message="boom")
+        assert Err(error) == Err(error)
+        assert Err(error) != err_msg("other")
+
+    def test_not_equal_to_other_types(self) -> None:
+        assert Ok(1) != 1
+        assert Ok(1) != Some(1)
+
+
+class TestResultHash:
+    """Test Result hashing."""
+
+    def test_equal_results_hash_equal(self) -> None:
+        assert hash(Ok(1)) == hash(Ok(1))
+
+    def test_usable_in_sets(self) -> None:
+        assert len({Ok(1), Ok(1), Ok(2)}) == 2
+
+    def test_unhashable_payload_raises(self) -> None:
+        with pytest.raises(TypeError):
+            hash(Ok([1]))
+
+
+class TestResultRepr:
+    """Test Result repr."""
+
+    def test_ok_repr(self) -> None:
+        assert repr(Ok(1)) == "Ok(1)"
+        assert repr(Ok("a")) == "Ok('a')"
+
+    def test_err_repr(self) -> None:
+        assert repr(err_msg("boom")).startswith("Err(Error(")
+
+
+class TestResultBool:
+    """Test that Result refuses implicit truthiness."""
+
+    def test_bool_raises(self) -> None:
+        with pytest.raises(TypeError, match="is_ok"):
+            bool(Ok(1))
+        with pytest.raises(TypeError, match="is_ok"):
+            bool(err_msg("boom"))
@@ -8,9 +8,11 @@ intermediate functions to satisfy strict type checking.
 
 from __future__ import annotations
 
+import pickle
+
 import pytest
 
-from pyropust import Err, Ok, Result
+from pyropust import Err, Ok, PyropustError, Result
 from tests.support import SampleCode, err_msg, wrap_error
 
 
@@ -36,6 +38,39 @@ class TestResultExpect:
             res.expect("Line 1\nLine 2\nLine 3")
 
 
+class TestPyropustError:
+    """Test the PyropustError raised by unwrap()/expect() on Err."""
+
+    def test_unwrap_raises_with_error_attached(self) -> None:
+        res: Result[int] = err_msg("boom")
+        with pytest.raises(PyropustError, match=r"called unwrap\(\) on Err: .*boom") as exc_info:
+            res.unwrap()
+        assert exc_info.value.error == res.unwrap_err()
+
+    def test_expect_raises_with_error_attached(self) -> None:
+        res: Result[int] = err_msg("boom")
+        with pytest.raises(PyropustError, match="custom message") as exc_info:
+            res.expect("custom message")
+        assert exc_info.value.error.message == "boom"
+
+    def test_is_a_runtime_error(self) -> None:
+        assert issubclass(PyropustError, RuntimeError)
+        res: Result[int] = err_msg("boom")
+        with pytest.raises(RuntimeError):
+            res.unwrap()
+
+    def test_survives_pickle(self) -> None:
+        res: Result[int] = err_msg("boom")
+        try:
+            res.unwrap()
+        except PyropustError as exc:
+            restored = pickle.loads(pickle.dumps(exc))
+            assert isinstance(restored, PyropustError)
+            assert restored.error == exc.error
+        else:
+            pytest.fail("unwrap() on Err did not raise")
+
+
 class TestResultExpectErr:
     """Test Result.expect_err() for extracting Err value with custom error message."""
 
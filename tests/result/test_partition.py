@@ -0,0 +1,89 @@
+"""Tests for Result.partition() and the error-accumulating Result.collect_all()."""
+
+from __future__ import annotations
+
+from collections.abc import Iterator
+
+import pytest
+
+from pyropust import Err, ErrorKind, Ok, Result
+from tests.support import SampleCode, err_msg, new_error
+
+
+def rows() -> list[Result[int]]:
+    return [
+        Ok(1),
+        Err(new_error(code=SampleCode.VALIDATION, message="bad age", path=["age"])),
+        Ok(3),
+        Err(new_error(code=SampleCode.NOT_FOUND, message="unknown team")),
+    ]
+
+
+class TestResultPartition:
+    """Test splitting Results into Ok values and errors."""
+
+    def test_splits_values_and_errors_in_order(self) -> None:
+        oks, errs = Result.partition(rows())
+        assert oks == [1, 3]
+        assert [e.message for e in errs] == ["bad age", "unknown team"]
+
+    def test_consumes_iterators(self) -> None:
+        def results() -> Iterator[Result[int]]:
+            yield Ok(1)
+            yield err_msg("boom")
+
+        assert Result.partition(results()) == ([1], [err_msg("boom").unwrap_err()])
+        empty: list[Result[int]] = []
+        assert Result.partition(empty) == ([], [])
+
+    def test_rejects_non_result_items(self) -> None:
+        with pytest.raises(TypeError, match="partition expects Result values"):
+            Result.partition([None])  # type: ignore[list-item]
+
+
+class TestResultCollectAll:
+    """Test accumulating every error instead of stopping at the first."""
+
+    def test_all_ok_collects_values(self) -> None:
+        results: list[Result[int]] = [Ok(1), Ok(2)]
+        assert Result.collect_all(results) == Ok([1, 2])
+        assert Result.collect_all(results, into=tuple) == Ok((1, 2))
+
+    def test_aggregates_every_error(self) -> None:
+        err = Result.collect_all(rows()).unwrap_err()
+        assert err.code == "collect_all"
+        assert err.kind == ErrorKind.InvalidInput
+        assert err.message == "2 of 4 items failed"
+        assert [child.message for child in err.children] == ["bad age", "unknown team"]
+
+    def test_records_index_in_path(self) -> None:
+        err = Result.collect_all(rows()).unwrap_err()
+        assert [child.path for child in err.children] == [[1, "age"], [3]]
+
+    def test_custom_code_and_message(self) -> None:
+        err = Result.collect_all(
+            rows(), code=SampleCode.VALIDATION, message="import failed"
+        ).unwrap_err()
+        assert err.code == "validation"
+        assert err.message == "import failed"
+
+    def test_consumes_everything_after_an_error(self) -> None:
+        consumed: list[int] = []
+
+        def results() -> Iterator[Result[int]]:
+            for i in range(4):
+                consumed.append(i)
+                yield err_msg("boom") if i % 2 else Ok(i)
+
+        assert len(Result.collect_all(results()).unwrap_err().children) == 2
+        assert consumed == [0, 1, 2, 3]
+
+    def test_aggregate_round_trips(self) -> None:
+        err = Result.collect_all(rows()).unwrap_err()
+        assert type(err).from_json(err.to_json()) == err
+
+    def test_rejects_non_error_payloads(self) -> None:
+        res = err_msg("boom").map_err(lambda _e: "oops")  # type: ignore[arg-type,return-value]
+        results: list[Result[int]] = [Ok(1), res]
+        with pytest.raises(TypeError, match=r"collect_all expects Err\(Error\) values"):
+            Result.collect_all(results)
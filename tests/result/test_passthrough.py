@@ -0,0 +1,79 @@
+"""Tests that Result combinators which do not apply return the receiver itself."""
+
+from __future__ import annotations
+
+import copy
+
+import pytest
+
+from pyropust import Ok, Result
+from tests.support import err_msg
+
+
+class TestErrPassthrough:
+    """Test that Ok-side combinators return an Err unchanged."""
+
+    def test_map_returns_same_err(self) -> None:
+        res: Result[int] = err_msg("boom")
+        assert res.map(lambda x: x + 1) is res
+        assert res.and_then(lambda x: Ok(x + 1)) is res
+        assert res.map_try(int, code="parse", message="not an int") is res
+        assert res.and_then_try(lambda x: Ok(x), code="c", message="m") is res
+
+    def test_inspect_and_and_return_same_err(self) -> None:
+        res: Result[int] = err_msg("boom")
+        assert res.inspect(id) is res
+        assert res.and_(Ok(2)) is res
+
+
+class TestOkPassthrough:
+    """Test that Err-side combinators return an Ok unchanged."""
+
+    def test_error_combinators_return_same_ok(self) -> None:
+        res: Result[int] = Ok(1)
+        assert res.map_err(lambda e: e) is res
+        assert res.inspect_err(id) is res
+        assert res.or_else(lambda _: Ok(2)) is res
+        assert res.or_(Ok(2)) is res
+
+    def test_context_and_codes_return_same_ok(self) -> None:
+        res: Result[int] = Ok(1)
+        assert res.context("loading config") is res
+        assert res.with_code("config") is res
+        assert res.map_err_code("config") is res
+
+    def test_inspect_calls_callback_and_returns_same_ok(self) -> None:
+        seen: list[int] = []
+        res: Result[int] = Ok(1)
+        assert res.inspect(seen.append) is res
+        assert seen == [1]
+
+    def test_copy_returns_same_result(self) -> None:
+        res: Result[int] = Ok([1])
+        assert copy.copy(res) is res
+        assert copy.deepcopy(res) is not res
+
+
+class TestReturnedResults:
+    """Test that Results returned by callbacks or other arguments pass through as is."""
+
+    def test_and_then_returns_callback_result(self) -> None:
+        inner: Result[int] = Ok(2)
+        assert Ok(1).and_then(lambda _: inner) is inner
+
+    def test_or_else_returns_callback_result(self) -> None:
+        inner: Result[int] = Ok(2)
+        res: Result[int] = err_msg("boom")
+        assert res.or_else(lambda _: inner) is inner
+
+    def test_and_returns_other(self) -> None:
+        other: Result[int] = Ok(2)
+        assert Ok(1).and_(other) is other
+
+    def test_flatten_returns_inner_result(self) -> None:
+        inner: Result[int] = Ok(2)
+        assert Ok(inner).flatten() is inner
+
+    def test_non_result_callback_value_still_raises(self) -> None:
+        with pytest.raises(TypeError, match="and_then callback must return Result"):
+            Ok(1).and_then(lambda x: x)  # type: ignore[arg-type,return-value]
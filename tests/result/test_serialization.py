@@ -0,0 +1,116 @@
+"""Tests for Result wire formats (to_dict, from_dict, to_json, from_json)."""
+
+from __future__ import annotations
+
+import datetime as dt
+import json
+
+import pytest
+
+from pyropust import Err, Ok, Result
+from tests.support import SampleCode, err_msg, new_error, wrap_error
+
+
+def nested_err() -> Result[int]:
+    inner = new_error(code=SampleCode.ERROR, message="inner", path=["a", 0], metadata={"n": 1})
+    return Err(wrap_error(inner, code=SampleCode.CUSTOM, message="outer"))
+
+
+class TestResultDict:
+    """Test the {"ok": ...} / {"err": ...} dict envelope."""
+
+    def test_ok_envelope(self) -> None:
+        res: Result[list[int]] = Ok([1, 2])
+        assert res.to_dict() == {"ok": [1, 2]}
+        assert Result.from_dict({"ok": [1, 2]}) == res
+
+    def test_err_envelope_uses_error_dict(self) -> None:
+        res = nested_err()
+        data = res.to_dict()
+        assert data == {"err": res.unwrap_err().to_dict()}
+        assert Result.from_dict(data) == res
+
+    def test_encoder_and_decoder_hooks(self) -> None:
+        res: Result[dt.date] = Ok(dt.date(2024, 1, 2))
+        data = res.to_dict(encoder=lambda d: d.isoformat())
+        assert data == {"ok": "2024-01-02"}
+        assert Result.from_dict(data, decoder=dt.date.fromisoformat) == res
+
+    def test_hooks_do_not_touch_errors(self) -> None:
+        res: Result[int] = err_msg("boom")
+        data = res.to_dict(encoder=lambda _v: pytest.fail("encoder called"))
+        assert Result.from_dict(data, decoder=lambda _v: pytest.fail("decoder called")) == res
+
+    def test_rejects_malformed_envelopes(self) -> None:
+        for data in [{}, {"ok": 1, "err": {}}, {"value": 1}]:
+            with pytest.raises(ValueError, match="exactly one of 'ok' or 'err'"):
+                Result.from_dict(data)
+
+
+class TestResultJson:
+    """Test the versioned JSON envelope."""
+
+    def test_ok_round_trip(self) -> None:
+        res: Result[dict[str, object]] = Ok({"a": [1, 2.5, None, True]})
+        document = res.to_json()
+        assert json.loads(document) == {"schema_version": 1, "ok": {"a": [1, 2.5, None, True]}}
+        assert Result.from_json(document) == res
+        assert Result.from_json(document.encode()) == res
+
+    def test_err_round_trip_keeps_cause_chain(self) -> None:
+        res = nested_err()
+        document = json.loads(res.to_json())
+        assert document["schema_version"] == 1
+        assert "schema_version" not in document["err"]
+        assert document["err"]["cause"]["path"] == ["a", 0]
+        assert Result.from_json(res.to_json()) == res
+
+    def test_err_round_trip_at_the_depth_limit(self) -> None:
+        # The envelope adds one level above the error chain.
+        error = new_error(code=SampleCode.NOT_FOUND, message="row missing")
+        for index in range(124):
+            error = wrap_error(error, code=SampleCode.ERROR, message=f"layer {index}")
+        res: Result[int] = Err(error)
+        assert Result.from_json(res.to_json()) == res
+        deeper: Result[int] = Err(wrap_error(error, code=SampleCode.ERROR, message="one more"))
+        with pytest.raises(ValueError, match="nested more than 127 levels"):
+            deeper.to_json()
+
+    def test_encoder_and_decoder_hooks(self) -> None:
+        res: Result[dt.date] = Ok(dt.date(2024, 1, 2))
+        document = res.to_json(encoder=lambda d: d.isoformat())
+        assert json.loads(document)["ok"] == "2024-01-02"
+        assert Result.from_json(document, decoder=dt.date.fromisoformat) == res
+
+    def test_payload_nesting_limit_counts_the_envelope(self) -> None:
+        payload: list[object] = []
+        for _ in range(125):
+            payload = [payload]
+        res: Result[list[object]] = Ok(payload)
+        assert Result.from_json(res.to_json()) == res
+        with pytest.raises(ValueError, match="nested more than 126 levels"):
+            Ok([payload]).to_json()
+
+    def test_non_json_payload_needs_encoder(self) -> None:
+        res: Result[object] = Ok(object())
+        with pytest.raises(TypeError, match="JSON-compatible"):
+            res.to_json()
+
+    def test_only_error_payloads_serialize(self) -> None:
+        res = err_msg("boom").map_err(lambda _e: "oops")  # type: ignore[arg-type,return-value]
+        with pytest.raises(TypeError, match=r"only Err\(Error\)"):
+            res.to_json()
+
+    def test_rejects_invalid_documents(self) -> None:
+        cases: list[tuple[str, str]] = [
+            ("{", "invalid Result JSON"),
+            ('{"ok": 1}', "missing 'schema_version'"),
+            ('{"schema_version": 2, "ok": 1}', "unsupported schema_version 2"),
+            ('{"schema_version": 1}', "exactly one of 'ok' or 'err'"),
+            ('{"schema_version": 1, "ok": 1, "extra": 1}', "unknown field 'extra'"),
+            ('{"schema_version": 1, "err": "boom"}', "'err' must be an object"),
+            ('{"schema_version": 1, "err": {"kind": "Internal"}}', "missing 'err.code'"),
+        ]
+        for document, message in cases:
+            with pytest.raises(ValueError, match=message):
+                Result.from_json(document)
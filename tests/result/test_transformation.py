@@ -56,7 +56,7 @@ class TestResultMapTry:
         assert err.code == SampleCode.BAD_INPUT
         assert err.message == "invalid int"
         assert err.cause is not None
-        assert "py_exception" in err.cause
+        assert err.cause.code == "py_exception"
 
     def test_map_try_skips_on_err(self) -> None:
         res: Result[int] = err_msg("error").map_try(
@@ -91,7 +91,7 @@ class TestResultAndThenTry:
         assert err.code == SampleCode.BAD_INPUT
         assert err.message == "invalid int"
         assert err.cause is not None
-        assert "py_exception" in err.cause
+        assert err.cause.code == "py_exception"
 
     def test_and_then_try_skips_on_err(self) -> None:
         res: Result[str] = err_msg("error").and_then_try(
@@ -40,7 +40,7 @@ def new_error(
     path: list[str | int] | None = None,
     expected: str | None = None,
     got: str | None = None,
-    metadata: Mapping[str, str] | None = None,
+    metadata: Mapping[str, object] | None = None,
 ) -> Error:
     return Error.new(
         code=code,
@@ -64,7 +64,7 @@ def wrap_error(
     path: list[str | int] | None = None,
     expected: str | None = None,
     got: str | None = None,
-    metadata: Mapping[str, str] | None = None,
+    metadata: Mapping[str, object] | None = None,
 ) -> Error:
     return Error.wrap(
         err_value,
@@ -0,0 +1,93 @@
+"""Tests for async exception boundaries (Result.attempt_async, @catch on coroutines)."""
+
+from __future__ import annotations
+
+import asyncio
+import inspect
+
+import pytest
+
+from pyropust import Error, Result, catch
+from tests.support import err_msg
+
+
+async def divide(a: int, b: int) -> float:
+    await asyncio.sleep(0)
+    return a / b
+
+
+class TestResultAttemptAsync:
+    """Test Result.attempt_async() for awaitables."""
+
+    def test_attempt_async_wraps_value(self) -> None:
+        result = asyncio.run(Result.attempt_async(divide(10, 2)))
+        assert result.is_ok()
+        assert result.unwrap() == 5.0
+
+    def test_attempt_async_accepts_callable(self) -> None:
+        result = asyncio.run(Result.attempt_async(lambda: divide(9, 3)))
+        assert result.unwrap() == 3.0
+
+    def test_attempt_async_converts_matching_exception(self) -> None:
+        result = asyncio.run(Result.attempt_async(divide(1, 0), ZeroDivisionError))
+        assert result.is_err()
+        error = result.unwrap_err()
+        assert isinstance(error, Error)
+        assert error.code == "py_exception"
+        assert error.metadata["exception"] == "ZeroDivisionError"
+
+    def test_attempt_async_reraises_non_matching_exception(self) -> None:
+        with pytest.raises(ZeroDivisionError):
+            asyncio.run(Result.attempt_async(divide(1, 0), ValueError))
+
+    def test_attempt_async_passes_through_result(self) -> None:
+        async def load() -> Result[int]:
+            await asyncio.sleep(0)
+            return err_msg("not found")
+
+        result = asyncio.run(Result.attempt_async(load()))
+        assert result.is_err()
+        assert result.unwrap_err().message == "not found"
+
+    def test_attempt_async_propagates_cancellation(self) -> None:
+        async def slow() -> int:
+            await asyncio.sleep(10)
+            return 1
+
+        async def main() -> None:
+            task = asyncio.ensure_future(Result.attempt_async(slow(), BaseException))
+            await asyncio.sleep(0)
+            task.cancel()
+            with pytest.raises(asyncio.CancelledError):
+                await task
+
+        asyncio.run(main())
+
+    def test_attempt_async_rejects_non_awaitable(self) -> None:
+        with pytest.raises(TypeError, match="attempt_async expects an awaitable"):
+            Result.attempt_async(lambda: 1)  # type: ignore[arg-type, return-value]
+
+
+class TestCatchAsync:
+    """Test @catch on coroutine functions."""
+
+    def test_catch_keeps_coroutine_function(self) -> None:
+        @catch(ZeroDivisionError)
+        async def safe_divide(a: int, b: int) -> float:
+            return await divide(a, b)
+
+        assert inspect.iscoroutinefunction(safe_divide)
+        assert asyncio.run(safe_divide(4, 2)).unwrap() == 2.0
+
+        result = asyncio.run(safe_divide(1, 0))
+        assert result.is_err()
+        assert result.unwrap_err().metadata["exception"] == "ZeroDivisionError"
+
+    def test_bare_catch_on_coroutine_function(self) -> None:
+        @catch
+        async def parse(value: str) -> int:
+            await asyncio.sleep(0)
+            return int(value)
+
+        assert asyncio.run(parse("12")).unwrap() == 12
+        assert asyncio.run(parse("x")).is_err()
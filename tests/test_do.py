@@ -0,0 +1,357 @@
+"""Tests for the @do generator decorator."""
+
+from __future__ import annotations
+
+import asyncio
+import gc
+import inspect
+import pickle
+import weakref
+from collections.abc import AsyncGenerator, Callable, Generator
+from typing import Any
+
+import pytest
+
+from pyropust import None_, Ok, Option, Result, Some, do
+from tests.support import SampleCode, err_msg
+
+
+def parse_int(value: str) -> Result[int]:
+    if value.isdigit():
+        return Ok(int(value))
+    return err_msg(f"not a number: {value}", SampleCode.BAD_INPUT)
+
+
+@do
+def double(value: str) -> Generator[Result[Any], Any, int]:
+    parsed = yield parse_int(value)
+    return parsed * 2
+
+
+class Holder:
+    pass
+
+
+class TestDoResult:
+    """Test @do with Result-yielding generators."""
+
+    def test_do_sends_ok_values_and_wraps_return(self) -> None:
+        @do
+        def add(a: str, b: str) -> Generator[Result[Any], Any, int]:
+            x = yield parse_int(a)
+            y = yield parse_int(b)
+            return x + y
+
+        result = add("1", "2")
+        assert result.is_ok()
+        assert result.unwrap() == 3
+
+    def test_do_short_circuits_on_first_err(self) -> None:
+        reached: list[str] = []
+
+        @do
+        def pipeline() -> Generator[Result[Any], Any, int]:
+            x = yield parse_int("x")
+            reached.append("after first")
+            y = yield parse_int("y")
+            return x + y
+
+        result = pipeline()
+        assert result.is_err()
+        assert result.unwrap_err().message == "not a number: x"
+        assert reached == []
+
+    def test_do_closes_generator_on_err(self) -> None:
+        cleaned: list[bool] = []
+
+        @do
+        def pipeline() -> Generator[Result[Any], Any, int]:
+            try:
+                yield parse_int("bad")
+            finally:
+                cleaned.append(True)
+            return 0
+
+        assert pipeline().is_err()
+        assert cleaned == [True]
+
+    def test_do_without_yield_points(self) -> None:
+        @do
+        def constant() -> Generator[Result[Any], Any, str]:
+            return "done"
+            yield Ok(None)  # pragma: no cover
+
+        assert constant().unwrap() == "done"
+
+    def test_do_passes_through_returned_result(self) -> None:
+        @do
+        def pipeline() -> Generator[Result[Any], Any, Result[int]]:
+            x = yield Ok(1)
+            return err_msg(f"rejected {x}")
+
+        result = pipeline()
+        assert result.is_err()
+        assert result.unwrap_err().message == "rejected 1"
+
+    def test_do_propagates_exceptions(self) -> None:
+        @do
+        def pipeline() -> Generator[Result[Any], Any, int]:
+            yield Ok(1)
+            raise ValueError("boom")
+
+        with pytest.raises(ValueError, match="boom"):
+            pipeline()
+
+    def test_do_rejects_non_result_yield(self) -> None:
+        @do
+        def pipeline() -> Generator[Any, Any, int]:
+            yield 1
+            return 0
+
+        with pytest.raises(TypeError, match="do block must yield Result or Option"):
+            pipeline()
+
+    def test_do_rejects_mixed_yields(self) -> None:
+        @do
+        def pipeline() -> Generator[Any, Any, int]:
+            yield Ok(1)
+            yield Some(2)
+            return 0
+
+        with pytest.raises(TypeError, match="do block mixes Result and Option"):
+            pipeline()
+
+    def test_do_requires_generator_function(self) -> None:
+        @do  # type: ignore[call-overload]
+        def plain() -> int:
+            return 1
+
+        with pytest.raises(TypeError, match="do expects a generator or async generator function"):
+            plain()
+
+    def test_do_preserves_function_metadata(self) -> None:
+        @do
+        def documented() -> Generator[Result[Any], Any, int]:
+            """Docstring."""
+            value = yield Ok(1)
+            return value
+
+        assert documented.__name__ == "documented"
+        assert documented.__doc__ == "Docstring."
+
+    def test_do_on_methods(self) -> None:
+        class Service:
+            def __init__(self, base: int) -> None:
+                self.base = base
+
+            @do
+            def add(self, value: str) -> Generator[Result[Any], Any, int]:
+                parsed = yield parse_int(value)
+                return self.base + parsed
+
+        assert Service(10).add("5").unwrap() == 15
+
+
+class TestDoOption:
+    """Test @do with Option-yielding generators."""
+
+    def test_do_sends_some_values_and_wraps_return(self) -> None:
+        @do
+        def pair() -> Generator[Option[Any], Any, tuple[int, str]]:
+            a = yield Some(1)
+            b = yield Some("x")
+            return (a, b)
+
+        result = pair()
+        assert result.is_some()
+        assert result.unwrap() == (1, "x")
+
+    def test_do_short_circuits_on_none(self) -> None:
+        reached: list[str] = []
+
+        @do
+        def pipeline() -> Generator[Option[Any], Any, int]:
+            yield None_()
+            reached.append("after none")
+            return 1
+
+        result = pipeline()
+        assert result.is_none()
+        assert reached == []
+
+
+class TestDoFunctionObject:
+    """Test the object returned by @do as a Python function replacement."""
+
+    def test_wrapper_type_lives_in_pyropust(self) -> None:
+        assert type(double).__module__ == "pyropust"
+        assert double.__module__ == __name__
+
+    def test_wrapper_pickles_by_reference(self) -> None:
+        assert pickle.loads(pickle.dumps(double)) is double
+
+    def test_wrapper_keeps_custom_attributes(self) -> None:
+        @do
+        def tagged() -> Generator[Result[Any], Any, int]:
+            value = yield Ok(1)
+            return value
+
+        tagged.tag = "api"  # type: ignore[attr-defined]
+        assert tagged.tag == "api"  # type: ignore[attr-defined]
+        assert tagged.__dict__["tag"] == "api"
+
+    def test_wrapped_function_is_not_an_instance_attribute(self) -> None:
+        def source(value: int) -> Generator[Result[Any], Any, int]:
+            doubled = yield Ok(value * 2)
+            return doubled
+
+        wrapper = do(source)
+        assert wrapper.__wrapped__ is source  # type: ignore[attr-defined]
+        assert "__wrapped__" not in wrapper.__dict__
+        assert inspect.signature(wrapper) == inspect.signature(source)
+
+    def test_recursive_wrapper_is_collected(self) -> None:
+        def make(holder: Holder) -> Callable[[int], Result[Holder]]:
+            @do
+            def walk(depth: int) -> Generator[Result[Any], Any, Holder]:
+                found = yield Ok(holder)
+                return found if depth == 0 else walk(depth - 1).unwrap()
+
+            return walk
+
+        holder = Holder()
+        walk = make(holder)
+        assert walk(2).unwrap() is holder
+        assert gc.is_tracked(walk)
+        ref = weakref.ref(holder)
+        del holder, walk
+        gc.collect()
+        assert ref() is None
+
+
+async def fetch_int(value: str) -> Result[int]:
+    await asyncio.sleep(0)
+    return parse_int(value)
+
+
+class TestDoAsync:
+    """Test @do with async generator functions."""
+
+    def test_do_async_evaluates_to_last_yield(self) -> None:
+        @do
+        async def add(a: str, b: str) -> AsyncGenerator[Result[Any], Any]:
+            x = yield await fetch_int(a)
+            y = yield await fetch_int(b)
+            yield Ok(x + y)
+
+        result = asyncio.run(add("1", "2"))
+        assert result.is_ok()
+        assert result.unwrap() == 3
+
+    def test_do_async_short_circuits_on_first_err(self) -> None:
+        reached: list[str] = []
+        cleaned: list[bool] = []
+
+        @do
+        async def pipeline() -> AsyncGenerator[Result[Any], Any]:
+            try:
+                yield await fetch_int("bad")
+                reached.append("after err")
+                yield Ok(1)
+            finally:
+                await asyncio.sleep(0)
+                cleaned.append(True)
+
+        result = asyncio.run(pipeline())
+        assert result.is_err()
+        assert result.unwrap_err().message == "not a number: bad"
+        assert reached == []
+        assert cleaned == [True]
+
+    def test_do_async_without_yield_points(self) -> None:
+        @do
+        async def noop() -> AsyncGenerator[Result[Any], Any]:
+            await asyncio.sleep(0)
+            if False:  # pragma: no cover
+                yield Ok(None)
+
+        result = asyncio.run(noop())
+        assert result.is_ok()
+        assert result.unwrap() is None
+
+    def test_do_async_with_option(self) -> None:
+        @do
+        async def pipeline() -> AsyncGenerator[Option[Any], Any]:
+            value = yield Some(2)
+            await asyncio.sleep(0)
+            yield None_()
+            yield Some(value)  # pragma: no cover
+
+        assert asyncio.run(pipeline()).is_none()
+
+    def test_do_async_propagates_exceptions(self) -> None:
+        @do
+        async def pipeline() -> AsyncGenerator[Result[Any], Any]:
+            yield await fetch_int("1")
+            raise ValueError("boom")
+
+        with pytest.raises(ValueError, match="boom"):
+            asyncio.run(pipeline())
+
+    def test_do_async_rejects_non_result_yield(self) -> None:
+        @do
+        async def pipeline() -> AsyncGenerator[Any, Any]:
+            yield 1
+
+        with pytest.raises(TypeError, match="do block must yield Result or Option"):
+            asyncio.run(pipeline())
+
+    def test_do_async_forwards_cancellation(self) -> None:
+        cleaned: list[bool] = []
+
+        @do
+        async def slow() -> AsyncGenerator[Result[Any], Any]:
+            try:
+                await asyncio.sleep(10)
+                yield Ok(1)
+            finally:
+                cleaned.append(True)
+
+        async def main() -> None:
+            task = asyncio.ensure_future(slow())
+            await asyncio.sleep(0)
+            task.cancel()
+            with pytest.raises(asyncio.CancelledError):
+                await task
+
+        asyncio.run(main())
+        assert cleaned == [True]
+
+    def test_do_async_is_a_coroutine_function(self) -> None:
+        @do
+        async def pipeline() -> AsyncGenerator[Result[Any], Any]:
+            yield Ok(1)
+
+        assert inspect.iscoroutinefunction(pipeline)
+        assert not inspect.iscoroutinefunction(double)
+
+    def test_do_async_rejects_plain_coroutine_function(self) -> None:
+        @do  # type: ignore[call-overload]
+        async def plain() -> int:
+            return 1
+
+        with pytest.raises(TypeError, match="do expects a generator or async generator function"):
+            plain()
+
+    def test_do_async_runs_as_task(self) -> None:
+        @do
+        async def pipeline(value: str) -> AsyncGenerator[Result[Any], Any]:
+            parsed = yield await fetch_int(value)
+            yield Ok(parsed * 2)
+
+        async def main() -> list[Result[Any]]:
+            return list(await asyncio.gather(pipeline("1"), pipeline("x")))
+
+        first, second = asyncio.run(main())
+        assert first.unwrap() == 2
+        assert second.is_err()
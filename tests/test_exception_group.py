@@ -0,0 +1,136 @@
+"""Tests for ExceptionGroup handling at exception boundaries (except* semantics)."""
+
+from __future__ import annotations
+
+import asyncio
+
+import pytest
+
+from pyropust import Error, Result, catch, exception_to_error
+
+
+def raise_mixed() -> int:
+    raise ExceptionGroup("batch", [ValueError("a"), KeyError("b")])
+
+
+def raise_values() -> int:
+    raise ExceptionGroup("batch", [ValueError("a"), ValueError("b")])
+
+
+def raise_nested() -> int:
+    raise ExceptionGroup("batch", [ValueError("a"), ExceptionGroup("inner", [ValueError("b")])])
+
+
+class TestExceptionGroupSplit:
+    """Test splitting groups by the requested exception types."""
+
+    def test_fully_matched_group_becomes_aggregate_error(self) -> None:
+        err = Result.attempt(raise_values, ValueError).unwrap_err()
+        assert err.message == "ExceptionGroup: batch (2 sub-exceptions)"
+        assert err.metadata["exception"] == "ExceptionGroup"
+        assert [child.message for child in err.children] == ["ValueError: a", "ValueError: b"]
+        assert [child.metadata["exception"] for child in err.children] == [
+            "ValueError",
+            "ValueError",
+        ]
+
+    def test_default_types_match_all_exceptions(self) -> None:
+        err = Result.attempt(raise_mixed).unwrap_err()
+        assert len(err.children) == 2
+
+    def test_unmatched_remainder_is_reraised(self) -> None:
+        with pytest.raises(ExceptionGroup) as exc_info:
+            Result.attempt(raise_mixed, ValueError)
+        remainder = exc_info.value
+        assert isinstance(remainder, ExceptionGroup)
+        assert len(remainder.exceptions) == 1
+        assert isinstance(remainder.exceptions[0], KeyError)
+
+    def test_unmatched_group_propagates_unchanged(self) -> None:
+        with pytest.raises(ExceptionGroup) as exc_info:
+            Result.attempt(raise_mixed, TypeError)
+        assert len(exc_info.value.exceptions) == 2
+
+    def test_base_exception_leaves_are_not_caught_by_default(self) -> None:
+        def fail() -> int:
+            raise BaseExceptionGroup("batch", [ValueError("a"), KeyboardInterrupt()])
+
+        with pytest.raises(BaseExceptionGroup) as exc_info:
+            Result.attempt(fail)
+        assert [type(exc) for exc in exc_info.value.exceptions] == [KeyboardInterrupt]
+
+    def test_nested_groups_become_nested_aggregates(self) -> None:
+        err = Result.attempt(raise_nested, ValueError).unwrap_err()
+        assert [child.message for child in err.children] == [
+            "ValueError: a",
+            "ExceptionGroup: inner (1 sub-exception)",
+        ]
+        assert [child.message for child in err.children[1].children] == ["ValueError: b"]
+
+    def test_requesting_a_group_type_catches_the_whole_group(self) -> None:
+        err = Result.attempt(raise_mixed, ExceptionGroup).unwrap_err()
+        assert err.message == "ExceptionGroup: batch (2 sub-exceptions)"
+        assert len(err.children) == 2
+
+    def test_plain_exceptions_are_unaffected(self) -> None:
+        def fail() -> int:
+            raise ValueError("plain")
+
+        err = Result.attempt(fail, ValueError).unwrap_err()
+        assert err.children == []
+        with pytest.raises(ValueError, match="plain"):
+            Result.attempt(fail, KeyError)
+
+
+class TestExceptionGroupBoundaries:
+    """Test @catch and async boundaries with groups."""
+
+    def test_catch_decorator_splits_groups(self) -> None:
+        assert len(catch(ValueError)(raise_values)().unwrap_err().children) == 2
+        with pytest.raises(ExceptionGroup):
+            catch(ValueError)(raise_mixed)()
+
+    def test_task_group_failures(self) -> None:
+        async def fail(value: str) -> None:
+            raise ValueError(value)
+
+        async def run_all() -> None:
+            async with asyncio.TaskGroup() as tg:
+                tg.create_task(fail("a"))
+                tg.create_task(fail("b"))
+
+        err = asyncio.run(Result.attempt_async(run_all(), ValueError)).unwrap_err()
+        assert sorted(child.message for child in err.children) == [
+            "ValueError: a",
+            "ValueError: b",
+        ]
+
+    def test_async_remainder_is_reraised(self) -> None:
+        async def run() -> int:
+            return raise_mixed()
+
+        with pytest.raises(ExceptionGroup):
+            asyncio.run(Result.attempt_async(run, ValueError))
+
+
+class TestAggregateSerialization:
+    """Test children through exception_to_error and the wire formats."""
+
+    def test_exception_to_error_fills_children(self) -> None:
+        err = exception_to_error(ExceptionGroup("batch", [ValueError("a")]))
+        assert [child.message for child in err.children] == ["ValueError: a"]
+
+    def test_children_round_trip(self) -> None:
+        err = Result.attempt(raise_nested, ValueError).unwrap_err()
+        data = err.to_dict()
+        children = data["children"]
+        assert isinstance(children, list)
+        assert [child["message"] for child in children] == [
+            "ValueError: a",
+            "ExceptionGroup: inner (1 sub-exception)",
+        ]
+        assert Error.from_dict(data) == err
+        assert Error.from_json(err.to_json()) == err
+
+    def test_children_affect_equality(self) -> None:
+        assert Result.attempt(raise_values).unwrap_err() != Result.attempt(raise_mixed).unwrap_err()
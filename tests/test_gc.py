@@ -0,0 +1,118 @@
+"""Tests for collecting reference cycles through Result, Option, Error and awaitables."""
+
+from __future__ import annotations
+
+import asyncio
+import gc
+import weakref
+from collections.abc import AsyncGenerator, Callable
+from typing import Any
+
+from pyropust import Error, Ok, Option, Result, Some, do, exception_to_error
+
+
+class Holder:
+    value: object = None
+
+
+def is_collected(build: Callable[[Holder], object]) -> bool:
+    holder = Holder()
+    holder.value = build(holder)
+    ref = weakref.ref(holder)
+    del holder
+    gc.collect()
+    return ref() is None
+
+
+def fail_holding(holder: Holder) -> int:
+    local = holder
+    raise ValueError(type(local).__name__)
+
+
+class TestTracking:
+    """Test that containers are tracked by the garbage collector."""
+
+    def test_result_and_option_are_tracked(self) -> None:
+        assert gc.is_tracked(Ok([]))
+        assert gc.is_tracked(Some([]))
+
+
+class TestResultCycles:
+    """Test that cycles through Result payloads are collected."""
+
+    def test_ok_value_cycle_is_collected(self) -> None:
+        assert is_collected(Ok)
+
+    def test_err_value_cycle_is_collected(self) -> None:
+        def build(holder: Holder) -> Result[int]:
+            res: Result[int] = Result.attempt(lambda: fail_holding(holder))
+            return res.map_err(lambda _: holder)  # type: ignore[arg-type,return-value]
+
+        assert is_collected(build)
+
+    def test_mapped_result_cycle_is_collected(self) -> None:
+        assert is_collected(lambda holder: Ok(1).map(lambda _: holder))
+
+
+class TestOptionCycles:
+    """Test that cycles through Option payloads are collected."""
+
+    def test_some_value_cycle_is_collected(self) -> None:
+        def build(holder: Holder) -> Option[Holder]:
+            return Some(holder)
+
+        assert is_collected(build)
+
+
+class TestErrorCycles:
+    """Test that cycles through exceptions and tracebacks held by Error are collected."""
+
+    def test_retained_exception_cycle_is_collected(self) -> None:
+        def build(holder: Holder) -> Error:
+            exc = ValueError("boom")
+            exc.holder = holder  # type: ignore[attr-defined]
+            return exception_to_error(exc, keep_exception=True)
+
+        assert is_collected(build)
+
+    def test_traceback_cycle_is_collected(self) -> None:
+        def build(holder: Holder) -> Error:
+            return Result.attempt(lambda: fail_holding(holder)).unwrap_err()
+
+        assert is_collected(build)
+
+    def test_wrapped_traceback_cycle_is_collected(self) -> None:
+        def build(holder: Holder) -> Error:
+            res = Ok(1).map_try(lambda _: fail_holding(holder), code="wrap", message="m")
+            return res.unwrap_err()
+
+        assert is_collected(build)
+
+
+class TestAwaitableCycles:
+    """Test that cycles through pending awaitables are collected."""
+
+    def test_pending_attempt_async_cycle_is_collected(self) -> None:
+        def build(holder: Holder) -> object:
+            async def work() -> Holder:
+                await asyncio.sleep(0)
+                return holder
+
+            awaitable = Result.attempt_async(work)
+            awaitable.send(None)
+            return awaitable
+
+        assert is_collected(build)
+
+    def test_pending_do_async_cycle_is_collected(self) -> None:
+        def build(holder: Holder) -> object:
+            @do
+            async def pipeline() -> AsyncGenerator[Result[Any], Any]:
+                await asyncio.sleep(0)
+                yield Ok(holder)
+
+            awaitable = pipeline()
+            awaitable.send(None)
+            return awaitable
+
+        assert is_collected(build)
@@ -0,0 +1,95 @@
+"""Tests for structural pattern matching on Result and Option."""
+
+from __future__ import annotations
+
+from pyropust import Err, Error, None_, Ok, Option, Result, Some
+from tests.support import err_msg
+
+
+def describe_result(res: Result[int]) -> str:
+    match res:
+        case Ok(value):
+            return f"ok:{value}"
+        case Err(error):
+            return f"err:{error.message}"
+        case _:
+            return "unreachable"
+
+
+def describe_option(opt: Option[int]) -> str:
+    match opt:
+        case Some(value):
+            return f"some:{value}"
+        case None_():
+            return "none"
+        case _:
+            return "unreachable"
+
+
+class TestResultMatch:
+    """Test match statements against Ok / Err."""
+
+    def test_ok_binds_value(self) -> None:
+        assert describe_result(Ok(1)) == "ok:1"
+
+    def test_err_binds_error(self) -> None:
+        assert describe_result(err_msg("boom")) == "err:boom"
+
+    def test_results_from_methods_are_matchable(self) -> None:
+        res: Result[int] = Ok(1)
+        assert describe_result(res.map(lambda x: x + 1)) == "ok:2"
+        assert describe_result(res.and_then(lambda _: err_msg("late"))) == "err:late"
+
+    def test_nested_patterns(self) -> None:
+        res: Result[int] = Ok(5)
+        match res:
+            case Ok(0):
+                outcome = "zero"
+            case Ok(value) if value > 3:
+                outcome = "big"
+            case _:
+                outcome = "other"
+        assert outcome == "big"
+
+    def test_keyword_patterns(self) -> None:
+        match err_msg("boom"):
+            case Err(error=Error(message=message)):
+                assert message == "boom"
+            case _:
+                raise AssertionError("expected Err")
+
+    def test_variants_are_result_subclasses(self) -> None:
+        assert isinstance(Ok(1), Ok)
+        assert isinstance(Ok(1), Result)
+        assert isinstance(err_msg("boom"), Err)
+        assert not isinstance(Ok(1), Err)
+
+    def test_payload_properties(self) -> None:
+        assert Ok(3).value == 3
+        assert err_msg("boom").error.message == "boom"
+        assert Ok.__match_args__ == ("value",)
+        assert Err.__match_args__ == ("error",)
+
+
+class TestOptionMatch:
+    """Test match statements against Some / None_."""
+
+    def test_some_binds_value(self) -> None:
+        assert describe_option(Some(2)) == "some:2"
+
+    def test_none_matches(self) -> None:
+        assert describe_option(None_()) == "none"
+
+    def test_options_from_methods_are_matchable(self) -> None:
+        opt = Some(2)
+        assert describe_option(opt.map(lambda x: x * 10)) == "some:20"
+        assert describe_option(opt.filter(lambda x: x > 5)) == "none"
+        assert describe_option(Ok(4).ok()) == "some:4"
+
+    def test_variants_are_option_subclasses(self) -> None:
+        assert isinstance(Some(1), Some)
+        assert isinstance(Some(1), Option)
+        assert isinstance(None_(), None_)
+        assert not isinstance(None_(), Some)
+        assert Some.__match_args__ == ("value",)
+        assert None_.__match_args__ == ()
@@ -0,0 +1,125 @@
+"""Tests for pickle and copy support on Result, Option and Error."""
+
+from __future__ import annotations
+
+import copy
+import os
+import pickle
+import subprocess
+import sys
+from concurrent.futures import ProcessPoolExecutor
+from pathlib import Path
+
+from pyropust import Err, Error, ErrorKind, None_, Ok, Option, Result, Some
+from tests.support import SampleCode, err_msg, new_error
+
+
+ROOT = Path(__file__).resolve().parent.parent
+
+# Unpickles an error from stdin and prints the kinds of it, its child and the child's cause.
+LOAD_KINDS = """
+import pickle, sys
+error = pickle.loads(sys.stdin.buffer.read())
+child = error.children[0]
+print(error.kind.value, child.kind.value, child.cause.kind.value)
+"""
+
+
+def parse_in_worker(value: str) -> Result[int]:
+    return Result.attempt(lambda: int(value), ValueError)
+
+
+class TestPickleRoundtrip:
+    """Test pickle round-trips across protocols."""
+
+    def test_result_roundtrip(self) -> None:
+        results: list[Result[object]] = [Ok(1), Ok({"a": [1, 2]}), err_msg("boom")]
+        for protocol in range(pickle.HIGHEST_PROTOCOL + 1):
+            for res in results:
+                restored = pickle.loads(pickle.dumps(res, protocol))
+                assert restored == res
+                assert type(restored) is type(res)
+
+    def test_option_roundtrip(self) -> None:
+        options: list[Option[int]] = [Some(3), None_()]
+        for protocol in range(pickle.HIGHEST_PROTOCOL + 1):
+            for opt in options:
+                restored = pickle.loads(pickle.dumps(opt, protocol))
+                assert restored == opt
+                assert type(restored) is type(opt)
+
+    def test_err_with_non_error_payload_roundtrip(self) -> None:
+        res = err_msg("boom").map_err(
+            lambda e: {"code": e.code},  # type: ignore[arg-type,return-value]
+        )
+        for protocol in range(pickle.HIGHEST_PROTOCOL + 1):
+            restored = pickle.loads(pickle.dumps(res, protocol))
+            assert isinstance(restored, Err)
+            assert restored.unwrap_err() == {"code": "error"}
+
+    def test_error_roundtrip_keeps_all_fields(self) -> None:
+        error = new_error(
+            code=SampleCode.ERROR,
+            message="age must be a number",
+            kind=ErrorKind.NotFound,
+            op="ParseAge",
+            path=["user", 0],
+            expected="numeric string",
+            got="x",
+            metadata={"source": "input"},
+        )
+        restored = pickle.loads(pickle.dumps(error))
+        assert restored == error
+        assert restored.path == ["user", 0]
+        assert restored.kind == ErrorKind.NotFound
+
+    def test_error_kind_roundtrip(self) -> None:
+        assert pickle.loads(pickle.dumps(ErrorKind.Internal)) == ErrorKind.Internal
+
+    def test_custom_kinds_load_in_fresh_process(self) -> None:
+        cause = Error.new("inner", "lost", kind=ErrorKind.register("PickleCause"))
+        child_kind = ErrorKind.register("PickleChild")
+        child = Error.wrap(cause, code="child", message="failed", kind=child_kind)
+        error = Error.group("batch", "1 failed", [child], kind=ErrorKind.register("PickleGroup"))
+        env = {**os.environ, "PYTHONPATH": str(ROOT)}
+        completed = subprocess.run(  # noqa: S603
+            [sys.executable, "-c", LOAD_KINDS],
+            input=pickle.dumps(error),
+            capture_output=True,
+            check=True,
+            env=env,
+        )
+        assert completed.stdout.split() == [b"PickleGroup", b"PickleChild", b"PickleCause"]
+
+    def test_result_crosses_process_pool(self) -> None:
+        with ProcessPoolExecutor(max_workers=1) as pool:
+            results = list(pool.map(parse_in_worker, ["1", "x"]))
+        assert results[0] == Ok(1)
+        assert isinstance(results[1], Err)
+        assert results[1].unwrap_err().metadata["exception"] == "ValueError"
+
+
+class TestCopy:
+    """Test copy.copy / copy.deepcopy."""
+
+    def test_copy_shares_payload(self) -> None:
+        payload = [1]
+        assert copy.copy(Ok(payload)).unwrap() is payload
+        assert copy.copy(Some(payload)).unwrap() is payload
+
+    def test_deepcopy_copies_payload(self) -> None:
+        payload = [1]
+        res = copy.deepcopy(Ok(payload))
+        opt = copy.deepcopy(Some(payload))
+        assert res == Ok([1])
+        assert res.unwrap() is not payload
+        assert opt.unwrap() is not payload
+
+    def test_copy_keeps_variant(self) -> None:
+        assert isinstance(copy.deepcopy(err_msg("boom")), Err)
+        assert isinstance(copy.copy(None_()), None_)
+
+    def test_error_copies_are_equal(self) -> None:
+        error = Error.new(code="e", message="boom")
+        assert copy.copy(error) == error
+        assert copy.deepcopy(error) == error
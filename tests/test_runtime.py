@@ -174,7 +174,7 @@ def test_error_wrap_with_error() -> None:
     assert wrapped.code == SampleCode.BOOM
     assert wrapped.message == "failed to load user"
     assert wrapped.cause is not None
-    assert "code='error'" in wrapped.cause
+    assert wrapped.cause.code == "error"
     assert wrapped.metadata["source"] == "payload"
 
 
@@ -194,7 +194,7 @@ def test_error_wrap_with_exception() -> None:
     assert wrapped.code == SampleCode.BOOM
     assert wrapped.message == "invalid json payload"
     assert wrapped.cause is not None
-    assert "code='py_exception'" in wrapped.cause
+    assert wrapped.cause.code == "py_exception"
     assert wrapped.metadata["cause_exception"] == "ValueError"
     assert "cause_py_traceback" in wrapped.metadata
 
@@ -213,7 +213,7 @@ def test_result_context_wraps_error() -> None:
     assert wrapped_err.message == "failed to process"
     assert wrapped_err.metadata["step"] == "parse"
     assert wrapped_err.cause is not None
-    assert "message='boom'" in wrapped_err.cause
+    assert wrapped_err.cause.message == "boom"
 
 
 def test_result_context_ok_passthrough() -> None:
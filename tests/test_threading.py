@@ -0,0 +1,103 @@
+"""Tests for sharing immutable Result, Option and Error values between threads."""
+
+from __future__ import annotations
+
+import threading
+from collections.abc import Callable
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
+from pyropust import None_, Ok, Option, Result, Some
+from tests.support import err_msg
+
+WORKERS = 8
+ROUNDS = 200
+
+
+def fail() -> int:
+    raise ValueError("boom")
+
+
+def run_concurrently[T](fn: Callable[[], T]) -> list[T]:
+    barrier = threading.Barrier(WORKERS)
+
+    def worker() -> T:
+        barrier.wait()
+        return fn()
+
+    with ThreadPoolExecutor(max_workers=WORKERS) as pool:
+        futures = [pool.submit(worker) for _ in range(WORKERS)]
+        return [future.result() for future in futures]
+
+
+class TestImmutability:
+    """Test that Result and Option instances cannot be mutated."""
+
+    def test_result_attributes_are_read_only(self) -> None:
+        res: Result[int] = Ok(1)
+        with pytest.raises(AttributeError):
+            res.value = 2  # type: ignore[attr-defined]
+        with pytest.raises(AttributeError):
+            err_msg("boom").error = None  # type: ignore[attr-defined]
+
+    def test_option_attributes_are_read_only(self) -> None:
+        opt: Option[int] = Some(1)
+        with pytest.raises(AttributeError):
+            opt.value = 2  # type: ignore[attr-defined]
+        with pytest.raises(AttributeError):
+            None_().value = 1  # type: ignore[attr-defined]
+
+
+class TestSharedValues:
+    """Test combinators on values shared between threads."""
+
+    def test_shared_result_combinators(self) -> None:
+        ok: Result[int] = Ok(2)
+        failed: Result[int] = err_msg("boom")
+
+        def work() -> list[object]:
+            return [
+                (
+                    ok.map(lambda x: x * 2).and_then(lambda x: Ok(x + 1)).unwrap(),
+                    failed.map(lambda x: x * 2).unwrap_or(0),
+                    Result.collect([ok, ok]).unwrap(),
+                    len(Result.collect_all([failed, failed]).unwrap_err().children),
+                    ok == Ok(2),
+                )
+                for _ in range(ROUNDS)
+            ]
+
+        for results in run_concurrently(work):
+            assert results == [(5, 0, [2, 2], 2, True)] * ROUNDS
+
+    def test_shared_option_combinators(self) -> None:
+        some: Option[int] = Some(3)
+        none: Option[int] = None_()
+
+        def work() -> list[object]:
+            return [
+                (
+                    some.map(lambda x: x + 1).unwrap(),
+                    none.unwrap_or(-1),
+                    some.zip(Some("a")).unwrap(),
+                    Option.collect([some, none]).is_none(),
+                )
+                for _ in range(ROUNDS)
+            ]
+
+        for results in run_concurrently(work):
+            assert results == [(4, -1, (3, "a"), True)] * ROUNDS
+
+    def test_shared_error_traceback_is_formatted_once(self) -> None:
+        err = Result.attempt(fail).unwrap_err()
+        texts = run_concurrently(lambda: err.metadata["py_traceback"])
+        assert len(set(texts)) == 1
+        assert all(frames == err.frames for frames in run_concurrently(lambda: err.frames))
+
+    def test_concurrent_conversions(self) -> None:
+        def work() -> list[str]:
+            return [Result.attempt(fail).unwrap_err().message for _ in range(ROUNDS)]
+
+        for messages in run_concurrently(work):
+            assert messages == ["ValueError: boom"] * ROUNDS
@@ -11,20 +11,24 @@ Run with:
 
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Never, assert_type
+from collections.abc import AsyncGenerator, Coroutine, Generator
+from typing import TYPE_CHECKING, Any, Never, assert_type
 
 from pyropust import (
     Err,
     Error,
     ErrorCode,
     ErrorKind,
+    Frame,
     None_,
     Ok,
     Option,
+    PyropustError,
     Result,
     Some,
     bail,
     catch,
+    do,
     ensure,
     err,
 )
@@ -78,6 +82,12 @@ if TYPE_CHECKING:
     # unwrap_err returns the Err value type
     assert_type(res.unwrap_err(), Error)
 
+    # unwrap / expect raise PyropustError carrying the Error
+    def get_pyropust_error() -> PyropustError:
+        raise NotImplementedError
+
+    assert_type(get_pyropust_error().error, Error)
+
     # unwrap_or_raise returns ok value type
     assert_type(res.unwrap_or_raise(RuntimeError("boom")), int)
 
@@ -109,6 +119,29 @@ if TYPE_CHECKING:
     chained = res.and_then(validate)
     assert_type(chained, Result[str])
 
+    # to_dict / to_json envelopes, with optional decoders
+    assert_type(res.to_json(), str)
+    assert_type(Result.from_json("{}"), Result[Any])
+    assert_type(Result.from_json("{}", decoder=int), Result[int])
+    assert_type(Result.from_dict({}, decoder=str), Result[str])
+
+    # collect gathers Ok values into a container
+    assert_type(Result.collect([res, res]), Result[list[int]])
+    assert_type(Result.collect([res], into=tuple), Result[tuple[int, ...]])
+    pairs: list[Result[tuple[str, int]]] = []
+    assert_type(Result.collect(pairs, into=dict), Result[dict[str, int]])
+    assert_type(Result.collect([res], into=frozenset), Result[frozenset[int]])
+    assert_type(Result.partition([res]), tuple[list[int], list[Error]])
+    assert_type(Result.collect_all([res]), Result[list[int]])
+    assert_type(Result.collect_all([res], into=tuple), Result[tuple[int, ...]])
+
+    # zip / all / map_n accumulate errors from independent Results
+    other: Result[str] = Ok("a")
+    assert_type(res.zip(other), Result[tuple[int, str]])
+    assert_type(res.zip_with(other, lambda n, s: s * n), Result[str])
+    assert_type(Result.all(res, other), Result[tuple[int, str]])
+    assert_type(Result.map_n(lambda n, s: f"{s}{n}", res, other), Result[str])
+
     # ==========================================================================
     # Result: Chaining (README example)
     # ==========================================================================
@@ -163,6 +196,14 @@ if TYPE_CHECKING:
     same_type_default = opt.unwrap_or(0)
     assert_type(same_type_default, int)
 
+    # to_dict / to_json envelopes, with optional decoders
+    assert_type(opt.to_dict(encoder=str), dict[str, object])
+    assert_type(Option.from_json(b"{}", decoder=int), Option[int])
+
+    # collect gathers Some values into a container
+    assert_type(Option.collect([opt, opt]), Option[list[int]])
+    assert_type(Option.collect([opt], into=tuple), Option[tuple[int, ...]])
+
     # ==========================================================================
     # Option: README example
     # ==========================================================================
@@ -176,6 +217,22 @@ if TYPE_CHECKING:
     name = name_opt.unwrap_or("Guest")
     assert_type(name, str)
 
+    # ==========================================================================
+    # Pattern matching
+    # ==========================================================================
+
+    match res:
+        case Ok(matched_value):
+            assert_type(matched_value, int)
+        case Err(matched_error):
+            assert_type(matched_error, Error)
+
+    match find_user(1):
+        case Some(matched_name):
+            assert_type(matched_name, str)
+        case None_():
+            pass
+
     # ==========================================================================
     # Result: Border control helpers
     # ==========================================================================
@@ -187,6 +244,43 @@ if TYPE_CHECKING:
     parsed = parse_int("123")
     assert_type(parsed, Result[int])
 
+    @catch(ValueError)
+    async def parse_int_async(value: str) -> int:
+        return int(value)
+
+    assert_type(parse_int_async("123"), Coroutine[Any, Any, Result[int]])
+
+    async def fetch_number() -> int:
+        return 1
+
+    assert_type(Result.attempt_async(fetch_number()), Coroutine[Any, Any, Result[int]])
+
+    # ==========================================================================
+    # do: generator short-circuiting
+    # ==========================================================================
+
+    @do
+    def add_parsed(a: str, b: str) -> Generator[Result[Any], Any, int]:
+        x = yield parse_int(a)
+        y = yield parse_int(b)
+        return x + y
+
+    assert_type(add_parsed("1", "2"), Result[int])
+
+    @do
+    def first_char(value: str) -> Generator[Option[Any], Any, str]:
+        text = yield Some(value)
+        return text[:1]
+
+    assert_type(first_char("abc"), Option[str])
+
+    @do
+    async def add_parsed_async(a: str) -> AsyncGenerator[Result[Any], Any]:
+        x = yield parse_int(a)
+        yield Ok(x + 1)
+
+    assert_type(add_parsed_async("1"), Coroutine[Any, Any, Result[Any]])
+
     # ==========================================================================
     # Error properties
     # ==========================================================================
@@ -199,12 +293,20 @@ if TYPE_CHECKING:
     assert_type(rope_err.kind, ErrorKind)
     assert_type(rope_err.code, str)
     assert_type(rope_err.message, str)
-    assert_type(rope_err.metadata, dict[str, str])
+    assert_type(rope_err.metadata, dict[str, object])
     assert_type(rope_err.op, str | None)
     assert_type(rope_err.path, list[str | int])
     assert_type(rope_err.expected, str | None)
     assert_type(rope_err.got, str | None)
-    assert_type(rope_err.cause, str | None)
+    assert_type(rope_err.cause, Error | None)
+    assert_type(rope_err.to_dict(strict=True), dict[str, object])
+    assert_type(rope_err.root_cause(), Error)
+    assert_type(rope_err.children, list[Error])
+    assert_type(rope_err.frames, list[Frame])
+    assert_type(rope_err.frames[0].line, str | None)
+    assert_type(Error.group("invalid", "form is invalid", [rope_err]), Error)
+    assert_type(rope_err.exception, BaseException | None)
+    assert_type(list(rope_err.chain()), list[Error])
 
     # ==========================================================================
     # ErrorKind class attributes
@@ -0,0 +1,54 @@
+"""Time combinators on the paths that return their receiver unchanged.
+
+Run against a release build (`uv run maturin develop --release`), e.g. `makers bench`:
+
+    python3 tools/bench_combinators.py [--number N]
+
+Each line shows the time per call and whether the call returned the receiver itself
+(`same`) or a new object (`new`).
+"""
+# ruff: noqa: T201  # Allow print() in this tool
+
+from __future__ import annotations
+
+import argparse
+import timeit
+from collections.abc import Callable
+
+from pyropust import Err, Error, None_, Ok, Option, Result, Some
+
+
+def _cases() -> list[tuple[str, object, Callable[[], object]]]:
+    ok: Result[int] = Ok(1)
+    err: Result[int] = Err(Error.new("bench", "failed"))
+    some: Option[int] = Some(1)
+    none: Option[int] = None_()
+    return [
+        ("Err.map", err, lambda: err.map(str)),
+        ("Err.and_then", err, lambda: err.and_then(lambda _: ok)),
+        ("Ok.map_err", ok, lambda: ok.map_err(lambda e: e)),
+        ("Ok.context", ok, lambda: ok.context("while benchmarking")),
+        ("Ok.with_code", ok, lambda: ok.with_code("bench")),
+        ("Ok.inspect", ok, lambda: ok.inspect(id)),
+        ("Ok.or_else", ok, lambda: ok.or_else(lambda _: err)),
+        ("Ok.and_then(Ok)", ok, lambda: ok.and_then(lambda _: ok)),
+        ("Some.inspect", some, lambda: some.inspect(id)),
+        ("Some.filter", some, lambda: some.filter(bool)),
+        ("None_.map", none, lambda: none.map(str)),
+        ("None_()", none, None_),
+    ]
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Time combinator passthrough paths.")
+    parser.add_argument("--number", type=int, default=1_000_000)
+    args = parser.parse_args()
+
+    for name, receiver, call in _cases():
+        seconds = min(timeit.repeat(call, number=args.number, repeat=5))
+        identity = "same" if call() is receiver else "new"
+        print(f"{name:<18} {seconds / args.number * 1e9:8.1f} ns  {identity}")
+
+
+if __name__ == "__main__":
+    main()
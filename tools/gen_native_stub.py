@@ -1,7 +1,7 @@
 """Generate pyropust_native.pyi from pyropust/__init__.pyi.
 
 This script extracts the native module type definitions from the public API stub,
-excluding Python-only implementations (do, catch) that don't exist in the Rust module.
+excluding Python-only implementations (catch) that don't exist in the Rust module.
 
 Usage:
     python tools/gen_native_stub.py
@@ -10,8 +10,8 @@ Usage:
 
 from pathlib import Path
 
-# Imports used only by excluded definitions (do, Do type alias)
-UNUSED_IMPORTS_IN_NATIVE = ["Generator"]
+# Imports used only by excluded definitions
+UNUSED_IMPORTS_IN_NATIVE = ["Protocol"]
 
 HEADER = """\
 # Auto-generated by tools/gen_native_stub.py
@@ -45,18 +45,6 @@ def extract_native_stub(init_pyi_content: str) -> str:
     while i < len(lines):
         line = lines[i]
 
-        # Skip type Do = ... (single line)
-        if line.startswith("type Do"):
-            i += 1
-            continue
-
-        # Skip def do (multi-line block ending with ...)
-        if line.startswith("def do"):
-            while i < len(lines) and not lines[i].strip().endswith("..."):
-                i += 1
-            i += 1
-            continue
-
         # Skip catch-related blocks (overloads and main definition)
         if "# Overload" in line and "catch" in line:
             # Skip comment, @overload decorator, and def line
@@ -73,6 +61,12 @@ def extract_native_stub(init_pyi_content: str) -> str:
             i += 1  # Skip the ... line
             continue
 
+        if line.startswith("class _CatchDecorator"):
+            i += 1
+            while i < len(lines) and (lines[i].startswith(" ") or lines[i] == ""):
+                i += 1
+            continue
+
         if line.startswith("def catch"):
             while i < len(lines) and not lines[i].strip().endswith("..."):
                 i += 1